@@ -0,0 +1,89 @@
+//! Generates the registry of day modules, so adding `src/days/YYYY/dayNN.rs`
+//! is all it takes to register a new solver.
+
+use std::{fmt::Write, path::Path};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let days_dir = root.join("src/days");
+    println!("cargo::rerun-if-changed={}", days_dir.display());
+
+    let mut years: Vec<u16> = std::fs::read_dir(&days_dir)
+        .expect("src/days should exist")
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let year = entry.file_name().into_string().ok()?;
+            let is_dir = entry.file_type().ok()?.is_dir();
+            (is_dir && year.len() == 4).then(|| year.parse().ok())?
+        })
+        .collect();
+    years.sort_unstable();
+
+    let mut event_days: Vec<(u16, Vec<usize>)> = Vec::new();
+    for &year in &years {
+        let year_dir = days_dir.join(year.to_string());
+        println!("cargo::rerun-if-changed={}", year_dir.display());
+        let mut days: Vec<usize> = std::fs::read_dir(&year_dir)
+            .expect("year directory should be readable")
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let day = name.strip_prefix("day")?.strip_suffix(".rs")?;
+                (day.len() == 2).then(|| day.parse().ok())?
+            })
+            .collect();
+        days.sort_unstable();
+        event_days.push((year, days));
+    }
+
+    let mut out = String::new();
+    for (year, days) in &event_days {
+        writeln!(out, "pub mod y{year} {{").unwrap();
+        for day in days {
+            let path = days_dir.join(format!("{year}/day{day:02}.rs"));
+            writeln!(
+                out,
+                "    #[path = {:?}]\n    pub mod day{day:02};",
+                path.display()
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    writeln!(out, "\nstatic SOLVERS: &[&dyn DynSolver] = &[").unwrap();
+    for (year, days) in &event_days {
+        for day in days {
+            writeln!(out, "    &y{year}::day{day:02}::Day{day:02},").unwrap();
+        }
+    }
+    writeln!(out, "];").unwrap();
+
+    // Only inputs that are present are embedded, the rest are read at runtime.
+    // Watching a file that doesn't exist would rerun this script every build.
+    let embedding = std::env::var_os("CARGO_FEATURE_EMBEDDED_INPUT").is_some();
+    writeln!(
+        out,
+        "\n#[cfg(feature = \"embedded-input\")]\nstatic INPUT: &[(u16, usize, &str)] = &["
+    )
+    .unwrap();
+    for (year, days) in &event_days {
+        for day in days {
+            let path = root.join(format!("input/{year}-{day:02}.txt"));
+            if path.exists() {
+                if embedding {
+                    println!("cargo::rerun-if-changed={}", path.display());
+                }
+                writeln!(
+                    out,
+                    "    ({year}, {day}, include_str!({:?})),",
+                    path.display()
+                )
+                .unwrap();
+            }
+        }
+    }
+    writeln!(out, "];").unwrap();
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("days.rs"), out).unwrap();
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, fmt::Display, path::Path};
+
+use anyhow::Context;
+
+use crate::util::{Answer, Value};
+
+/// File holding the known answers for our puzzle inputs.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Known-correct answers keyed by year, day, input and part.
+///
+/// The file format is one answer per line as `year day part answer`, with
+/// blank lines and `#` comments ignored. The day is written `day/name` for
+/// the answer to one of the day's [named inputs](crate::input::named_inputs),
+/// rather than to our own input.
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: HashMap<(u16, usize, Option<String>, u8), String>,
+}
+
+impl KnownAnswers {
+    /// Load known answers from `path`, which may not exist yet.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).with_context(|| format!("In {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut answers = HashMap::new();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.splitn(4, ' ').collect();
+            let &[year, day, part, answer] = fields.as_slice() else {
+                anyhow::bail!("Line {}: expected `year day part answer`.", idx + 1);
+            };
+            let (day, input) = match day.split_once('/') {
+                Some((day, name)) => (day, Some(name.to_owned())),
+                None => (day, None),
+            };
+            let key = (
+                year.parse()
+                    .with_context(|| format!("Line {}: bad year {year:?}", idx + 1))?,
+                day.parse()
+                    .with_context(|| format!("Line {}: bad day {day:?}", idx + 1))?,
+                input,
+                match part {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => anyhow::bail!("Line {}: part must be 1 or 2.", idx + 1),
+                },
+            );
+            answers.insert(key, answer.trim().to_owned());
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, year: u16, day: usize, part: u8) -> Option<&str> {
+        self.get_for(None, year, day, part)
+    }
+
+    /// The known answer for the named `input`, or our own if `None`.
+    pub fn get_for(&self, input: Option<&str>, year: u16, day: usize, part: u8) -> Option<&str> {
+        self.answers
+            .get(&(year, day, input.map(str::to_owned), part))
+            .map(String::as_str)
+    }
+
+    /// Compare both parts of an answer computed for `year` against the known
+    /// answers.
+    pub fn check(&self, year: u16, answer: &Answer) -> [Verdict; 2] {
+        self.check_for(None, year, answer)
+    }
+
+    /// Like [`check`](Self::check), for an answer computed from the named
+    /// `input`, or our own if `None`.
+    pub fn check_for(&self, input: Option<&str>, year: u16, answer: &Answer) -> [Verdict; 2] {
+        let day = answer.day();
+        [
+            Verdict::of(answer.part_one(), self.get_for(input, year, day, 1)),
+            Verdict::of(answer.part_two(), self.get_for(input, year, day, 2)),
+        ]
+    }
+}
+
+/// The result of checking one part against the known answers.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The part was solved but there's no known answer for it.
+    Unknown,
+    /// The part wasn't solved, so there's nothing to check.
+    NotRun,
+}
+
+impl Verdict {
+    /// Compare a part's computed answer, if any, with the expected answer.
+    pub fn of(computed: Option<&Value>, expected: Option<&str>) -> Self {
+        match (computed, expected) {
+            (None, _) => Verdict::NotRun,
+            (Some(_), None) => Verdict::Unknown,
+            (Some(computed), Some(expected)) if computed.matches(expected) => Verdict::Pass,
+            (Some(computed), Some(expected)) => Verdict::Fail {
+                expected: expected.to_owned(),
+                actual: computed.to_string(),
+            },
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Pad rather than write so verdicts can be aligned in columns.
+        match self {
+            Verdict::Pass => f.pad("pass"),
+            Verdict::Fail { expected, actual } => {
+                f.pad(&format!("FAIL (got {actual}, expected {expected})"))
+            }
+            Verdict::Unknown => f.pad("unknown"),
+            Verdict::NotRun => f.pad("not run"),
+        }
+    }
+}
+
+/// The known answer for a day's part, for use in `*_known_answer` tests.
+#[cfg(test)]
+pub(crate) fn known_answer(year: u16, day: usize, part: u8) -> String {
+    static KNOWN: std::sync::OnceLock<KnownAnswers> = std::sync::OnceLock::new();
+    let known = KNOWN.get_or_init(|| {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE);
+        KnownAnswers::load(&path).expect("Known answers file failed to parse")
+    });
+    known
+        .get(year, day, part)
+        .unwrap_or_else(|| panic!("No known answer for {year} day {day} part {part}"))
+        .to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{KnownAnswers, Verdict};
+    use crate::util::{Answer, Parts};
+
+    static ANSWERS: &str = "\
+# year day part answer
+2025 3 1 357
+
+2025 3 2 3121910778619
+2025 9 1 50
+2025 9/alice 1 4771532800
+";
+
+    #[test]
+    fn parse_answers() -> anyhow::Result<()> {
+        let known = KnownAnswers::parse(ANSWERS)?;
+        assert_eq!(known.get(2025, 3, 1), Some("357"));
+        assert_eq!(known.get(2025, 3, 2), Some("3121910778619"));
+        assert_eq!(known.get(2025, 9, 2), None);
+        assert_eq!(known.get(2024, 3, 1), None);
+        assert_eq!(known.get_for(Some("alice"), 2025, 9, 1), Some("4771532800"));
+        assert_eq!(known.get_for(Some("alice"), 2025, 3, 1), None);
+        assert_eq!(known.get_for(Some("bob"), 2025, 9, 1), None);
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected() {
+        assert!(KnownAnswers::parse("2025 3 1").is_err());
+        assert!(KnownAnswers::parse("2025 3 3 1").is_err());
+        assert!(KnownAnswers::parse("year 3 1 1").is_err());
+    }
+
+    #[test]
+    fn check_answers() -> anyhow::Result<()> {
+        let known = KnownAnswers::parse(ANSWERS)?;
+        let day3 = Answer::new(3, Parts::Both).first(|| 357).second(|| 1);
+        assert_eq!(
+            known.check(2025, &day3),
+            [
+                Verdict::Pass,
+                Verdict::Fail {
+                    expected: "3121910778619".to_owned(),
+                    actual: "1".to_owned()
+                }
+            ]
+        );
+        let day9 = Answer::new(9, Parts::One).first(|| 50);
+        assert_eq!(known.check(2025, &day9), [Verdict::Pass, Verdict::NotRun]);
+        assert_eq!(
+            known.check(2024, &day9),
+            [Verdict::Unknown, Verdict::NotRun]
+        );
+        let day4 = Answer::new(4, Parts::Both).first(|| 13).second(|| 43);
+        assert_eq!(
+            known.check(2025, &day4),
+            [Verdict::Unknown, Verdict::Unknown]
+        );
+        Ok(())
+    }
+}
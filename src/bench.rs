@@ -0,0 +1,382 @@
+use std::{fmt::Write, path::PathBuf, time::Duration};
+
+use anyhow::Context;
+
+use crate::{
+    Parts, days,
+    input::{InputSource, Normalize},
+    util::Timings,
+};
+
+/// Settings for the `bench` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring, to warm caches and the allocator.
+    pub warmup: usize,
+    /// Timed runs used for the statistics.
+    pub runs: usize,
+    /// Where to save the results for a later comparison.
+    pub save: Option<PathBuf>,
+    /// Previously saved results to compare against.
+    pub compare: Option<PathBuf>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 10,
+            save: None,
+            compare: None,
+        }
+    }
+}
+
+/// A median slowdown beyond this fraction of the baseline is a regression.
+pub const REGRESSION_THRESHOLD: f64 = 0.10;
+
+/// The phases of a day's run that are measured.
+pub const PHASES: [&str; 4] = ["parse", "part_one", "part_two", "total"];
+
+/// Summary statistics over repeated runs of a phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarise a non-empty set of samples.
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        // Sample standard deviation, zero for a single run.
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Self {
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Benchmark results for one day, with stats for each phase that ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: usize,
+    pub phases: Vec<(&'static str, Stats)>,
+}
+
+impl DayBench {
+    /// The same day's results in `baseline`, if it has them.
+    fn previous<'a>(&self, baseline: &'a [DayBench]) -> Option<&'a DayBench> {
+        baseline
+            .iter()
+            .find(|prev| (prev.year, prev.day) == (self.year, self.day))
+    }
+
+    fn phase(&self, name: &str) -> Option<&Stats> {
+        self.phases
+            .iter()
+            .find(|(phase, _)| *phase == name)
+            .map(|(_, stats)| stats)
+    }
+}
+
+/// Repeatedly solve `year`'s `day`, reading its input only once.
+pub fn bench_day(
+    year: u16,
+    day: usize,
+    source: &InputSource,
+    parts: Parts,
+    normalize: Normalize,
+    options: &BenchOptions,
+) -> anyhow::Result<DayBench> {
+    if options.runs == 0 {
+        anyhow::bail!("Benchmarking needs at least one run.");
+    }
+    let Some(solver) = days::get_solver(year, day) else {
+        anyhow::bail!("Day {day} of {year} is not implemented yet.");
+    };
+    let input = normalize.for_solver(solver).apply(source.read(year, day)?);
+
+    for _ in 0..options.warmup {
+        solver.solve(&input, parts)?;
+    }
+    let mut timings: Vec<Timings> = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs {
+        timings.push(*solver.solve(&input, parts)?.timings());
+    }
+
+    let phases = PHASES
+        .into_iter()
+        .filter_map(|phase| {
+            let samples: Option<Vec<_>> = timings
+                .iter()
+                .map(|t| match phase {
+                    "parse" => t.parse,
+                    "part_one" => t.part_one,
+                    "part_two" => t.part_two,
+                    _ => Some(t.total()),
+                })
+                .collect();
+            samples.map(|samples| (phase, Stats::from_samples(samples)))
+        })
+        .collect();
+    Ok(DayBench { year, day, phases })
+}
+
+/// Render benchmark results as a table, compared against a baseline if given.
+pub fn render(benches: &[DayBench], baseline: Option<&[DayBench]>) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:<4} {:<5} {:<9} {:>10} {:>10} {:>10} {:>10}",
+        "Year", "Day", "Phase", "Min", "Median", "Mean", "Std dev"
+    )
+    .unwrap();
+    for bench in benches {
+        let previous = baseline.and_then(|b| bench.previous(b));
+        for (phase, stats) in &bench.phases {
+            write!(
+                out,
+                "{:<4} {:<5} {:<9} {:>10.1?} {:>10.1?} {:>10.1?} {:>10.1?}",
+                bench.year, bench.day, phase, stats.min, stats.median, stats.mean, stats.stddev
+            )
+            .unwrap();
+            if let Some(before) = previous.and_then(|prev| prev.phase(phase)) {
+                let change = change(before.median, stats.median);
+                write!(out, "  {:>+7.1}%", change * 100.0).unwrap();
+                if change > REGRESSION_THRESHOLD {
+                    write!(out, "  REGRESSION").unwrap();
+                }
+            }
+            writeln!(out).unwrap();
+        }
+    }
+    out
+}
+
+/// Relative change from `before` to `after`, positive when slower.
+fn change(before: Duration, after: Duration) -> f64 {
+    let before = before.as_nanos() as f64;
+    if before == 0.0 {
+        return 0.0;
+    }
+    (after.as_nanos() as f64 - before) / before
+}
+
+/// Phases whose median slowed down by more than [`REGRESSION_THRESHOLD`].
+/// Each is given as `(year, day, phase)`.
+pub fn regressions(benches: &[DayBench], baseline: &[DayBench]) -> Vec<(u16, usize, &'static str)> {
+    let mut regressed = Vec::new();
+    for bench in benches {
+        let Some(previous) = bench.previous(baseline) else {
+            continue;
+        };
+        for (phase, stats) in &bench.phases {
+            if let Some(before) = previous.phase(phase)
+                && change(before.median, stats.median) > REGRESSION_THRESHOLD
+            {
+                regressed.push((bench.year, bench.day, *phase));
+            }
+        }
+    }
+    regressed
+}
+
+/// Serialise results to the saved format, one phase per line as
+/// `year day phase min_ns median_ns mean_ns stddev_ns`.
+pub fn to_saved(benches: &[DayBench]) -> String {
+    let mut out = String::from("# year day phase min_ns median_ns mean_ns stddev_ns\n");
+    for bench in benches {
+        for (phase, stats) in &bench.phases {
+            writeln!(
+                out,
+                "{} {} {} {} {} {} {}",
+                bench.year,
+                bench.day,
+                phase,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+/// Parse results written by [`to_saved`].
+pub fn from_saved(s: &str) -> anyhow::Result<Vec<DayBench>> {
+    let mut benches: Vec<DayBench> = Vec::new();
+    for (idx, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<_> = line.split_whitespace().collect();
+        let &[year, day, phase, min, median, mean, stddev] = fields.as_slice() else {
+            anyhow::bail!("Line {}: expected 7 fields.", idx + 1);
+        };
+        let year: u16 = year
+            .parse()
+            .with_context(|| format!("Line {}: bad year {year:?}", idx + 1))?;
+        let day: usize = day
+            .parse()
+            .with_context(|| format!("Line {}: bad day {day:?}", idx + 1))?;
+        let Some(phase) = PHASES.into_iter().find(|p| *p == phase) else {
+            anyhow::bail!("Line {}: unknown phase {phase:?}", idx + 1);
+        };
+        let nanos = |s: &str| -> anyhow::Result<Duration> {
+            let n = s
+                .parse()
+                .with_context(|| format!("Line {}: bad duration {s:?}", idx + 1))?;
+            Ok(Duration::from_nanos(n))
+        };
+        let stats = Stats {
+            min: nanos(min)?,
+            median: nanos(median)?,
+            mean: nanos(mean)?,
+            stddev: nanos(stddev)?,
+        };
+        match benches.last_mut() {
+            Some(bench) if (bench.year, bench.day) == (year, day) => {
+                bench.phases.push((phase, stats))
+            }
+            _ => benches.push(DayBench {
+                year,
+                day,
+                phases: vec![(phase, stats)],
+            }),
+        }
+    }
+    Ok(benches)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{DayBench, Stats, from_saved, regressions, render, to_saved};
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            min: ms(median - 1),
+            median: ms(median),
+            mean: ms(median),
+            stddev: ms(1),
+        }
+    }
+
+    #[test]
+    fn stats_odd_number_of_samples() {
+        let s = Stats::from_samples(vec![ms(4), ms(2), ms(9)]);
+        assert_eq!(s.min, ms(2));
+        assert_eq!(s.median, ms(4));
+        assert_eq!(s.mean, ms(5));
+        assert_eq!(s.stddev, Duration::from_nanos(3_605_551));
+    }
+
+    #[test]
+    fn stats_even_number_of_samples() {
+        let s = Stats::from_samples(vec![ms(4), ms(2), ms(8), ms(6)]);
+        assert_eq!(s.median, ms(5));
+        assert_eq!(s.mean, ms(5));
+    }
+
+    #[test]
+    fn stats_single_sample() {
+        let s = Stats::from_samples(vec![ms(3)]);
+        assert_eq!(s.min, ms(3));
+        assert_eq!(s.median, ms(3));
+        assert_eq!(s.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn saved_results_round_trip() -> anyhow::Result<()> {
+        let benches = vec![
+            DayBench {
+                year: 2025,
+                day: 4,
+                phases: vec![("parse", stats(10)), ("total", stats(30))],
+            },
+            DayBench {
+                year: 2025,
+                day: 8,
+                phases: vec![("part_one", stats(20))],
+            },
+            DayBench {
+                year: 2024,
+                day: 8,
+                phases: vec![("part_one", stats(40))],
+            },
+        ];
+        assert_eq!(from_saved(&to_saved(&benches))?, benches);
+        assert!(from_saved("2025 4 parse 1 2 3").is_err());
+        assert!(from_saved("2025 4 phase 1 2 3 4").is_err());
+        assert!(from_saved("4 parse 1 2 3 4").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn regressions_are_flagged() {
+        let baseline = vec![DayBench {
+            year: 2025,
+            day: 8,
+            phases: vec![("part_one", stats(100)), ("part_two", stats(100))],
+        }];
+        let current = vec![DayBench {
+            year: 2025,
+            day: 8,
+            phases: vec![("part_one", stats(105)), ("part_two", stats(120))],
+        }];
+        assert_eq!(
+            regressions(&current, &baseline),
+            vec![(2025, 8, "part_two")]
+        );
+        let table = render(&current, Some(&baseline));
+        let lines: Vec<_> = table.lines().collect();
+        assert!(lines[0].starts_with("Year Day   Phase"));
+        assert!(lines[1].starts_with("2025 8     part_one"));
+        assert!(lines[1].ends_with("+5.0%"));
+        assert!(lines[2].ends_with("+20.0%  REGRESSION"));
+    }
+
+    #[test]
+    fn other_years_are_not_compared() {
+        let baseline = vec![DayBench {
+            year: 2024,
+            day: 8,
+            phases: vec![("part_one", stats(100))],
+        }];
+        let current = vec![DayBench {
+            year: 2025,
+            day: 8,
+            phases: vec![("part_one", stats(200))],
+        }];
+        assert!(regressions(&current, &baseline).is_empty());
+        let table = render(&current, Some(&baseline));
+        assert!(!table.contains('%'), "{table}");
+    }
+}
@@ -0,0 +1,609 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::{
+    Parts,
+    answers::ANSWERS_FILE,
+    bench::BenchOptions,
+    client::DEFAULT_BASE_URL,
+    days,
+    input::{INPUT_DIR, InputSource, Normalize},
+    report::Format,
+    submit::HISTORY_FILE,
+};
+
+/// What the binary has been asked to do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Command {
+    /// Solve the selected days and print their answers.
+    #[default]
+    Run,
+    /// Solve the selected days (all by default) and check the known answers.
+    Verify,
+    /// Repeatedly solve the selected days (all by default) and report timings.
+    Bench,
+    /// Download the selected days' inputs into the input directory.
+    Fetch,
+    /// Solve one part of a single day and submit the answer.
+    Submit,
+    /// Create the module for a new day from the template.
+    New,
+    /// Solve a single day again whenever its `--input` file changes.
+    Watch,
+    /// Solve a single day for each of its named inputs and compare them.
+    Compare,
+}
+
+/// Options for a run of the `aoc_2025` binary.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    /// The event the days are from.
+    pub year: u16,
+    pub days: Vec<usize>,
+    pub input: InputSource,
+    /// How the input is cleaned up before it's solved.
+    pub normalize: Normalize,
+    /// Solve the days' registered examples rather than their inputs.
+    pub example: bool,
+    pub parts: Parts,
+    pub format: Format,
+    pub answers: PathBuf,
+    pub bench: BenchOptions,
+    /// The Advent of Code website, or a stand-in for it.
+    pub base_url: String,
+    /// Where submitted answers are recorded.
+    pub history: PathBuf,
+    /// How many days to solve at once.
+    pub jobs: usize,
+    /// The puzzle title for a new day.
+    pub title: Option<String>,
+    /// Which spans and events to log and trace, like `RUST_LOG`.
+    pub log: Option<String>,
+    /// Where to write a Chrome trace-event file.
+    pub trace: Option<PathBuf>,
+}
+
+/// Parse the full command line (without the program name).
+///
+/// An optional leading command picks what to do, see [`Command`]: `verify`,
+/// `bench`, `fetch`, `submit <day> <part>`, `new <day>`, `watch <day>` or
+/// `compare <day>`. Positional arguments select days as described in
+/// [`parse_days`]. The flags are:
+///
+/// - `--year <year>`: the event, by default the latest with any solvers.
+/// - `--input <path>`: read the input from `path`, or stdin for `-`.
+/// - `--example`: solve each day's examples and check their answers.
+/// - `--part 1|2`: solve only that part of each day.
+/// - `--format text|markdown|json|csv`: how the results are printed.
+/// - `--answers <path>`: the known answers file.
+/// - `--jobs <n>`: solve up to `n` days at once, or one per CPU for 0.
+/// - `--raw`: don't [normalize](Normalize) the input.
+/// - `--trim-spaces`: also trim line ends where spaces don't matter.
+/// - `--warmup`, `--runs`, `--save`, `--compare`: see [`BenchOptions`].
+/// - `--base-url <url>`: where `fetch` and `submit` go.
+/// - `--history <path>`: where `submit` records answers.
+/// - `--title <title>`: the puzzle title for `new`.
+/// - `--log <filter>`: what's logged, like `RUST_LOG`.
+/// - `--trace <path>`: write a Chrome trace file of each day's phases.
+pub fn parse_args<S: AsRef<str>>(args: &[S]) -> anyhow::Result<Args> {
+    let mut command = Command::Run;
+    let mut year = None;
+    let mut day_specs = Vec::new();
+    let mut input = InputSource::Default;
+    let mut normalize = Normalize::default();
+    let mut raw = false;
+    let mut example = false;
+    let mut parts = Parts::Both;
+    let mut format = Format::Text;
+    let mut answers = PathBuf::from(ANSWERS_FILE);
+    let mut bench = BenchOptions::default();
+    let mut base_url = DEFAULT_BASE_URL.to_owned();
+    let mut history = Path::new(INPUT_DIR).join(HISTORY_FILE);
+    let mut title = None;
+    let mut jobs = 1;
+    let mut log = None;
+    let mut trace = None;
+    let mut args = args.iter().map(AsRef::as_ref).peekable();
+    if args.next_if_eq(&"verify").is_some() {
+        command = Command::Verify;
+    } else if args.next_if_eq(&"bench").is_some() {
+        command = Command::Bench;
+    } else if args.next_if_eq(&"fetch").is_some() {
+        command = Command::Fetch;
+    } else if args.next_if_eq(&"submit").is_some() {
+        command = Command::Submit;
+    } else if args.next_if_eq(&"new").is_some() {
+        command = Command::New;
+    } else if args.next_if_eq(&"watch").is_some() {
+        command = Command::Watch;
+    } else if args.next_if_eq(&"compare").is_some() {
+        command = Command::Compare;
+    }
+    while let Some(arg) = args.next() {
+        match arg {
+            "--year" => {
+                let Some(Ok(value)) = args.next().map(str::parse) else {
+                    anyhow::bail!("--year needs the year of an event, eg 2025.");
+                };
+                year = Some(value);
+            }
+            "--input" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--input needs a path, or - for stdin.");
+                };
+                input = InputSource::from_arg(value);
+            }
+            "--example" => example = true,
+            "--raw" => raw = true,
+            "--trim-spaces" => normalize.trailing_spaces = true,
+            "--part" => {
+                parts = match args.next() {
+                    Some("1") => Parts::One,
+                    Some("2") => Parts::Two,
+                    _ => anyhow::bail!("--part must be 1 or 2."),
+                };
+            }
+            "--format" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--format needs one of text, json, csv or markdown.");
+                };
+                format = value.parse()?;
+            }
+            "--answers" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--answers needs a path.");
+                };
+                answers = PathBuf::from(value);
+            }
+            "--jobs" => {
+                let Some(Ok(n)) = args.next().map(str::parse) else {
+                    anyhow::bail!("--jobs needs a number of threads.");
+                };
+                jobs = match n {
+                    0 => std::thread::available_parallelism().map_or(1, usize::from),
+                    n => n,
+                };
+            }
+            "--warmup" | "--runs" => {
+                let Some(Ok(n)) = args.next().map(str::parse) else {
+                    anyhow::bail!("{arg} needs a number of runs.");
+                };
+                if arg == "--warmup" {
+                    bench.warmup = n;
+                } else {
+                    bench.runs = n;
+                }
+            }
+            "--save" | "--compare" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("{arg} needs a path.");
+                };
+                if arg == "--save" {
+                    bench.save = Some(PathBuf::from(value));
+                } else {
+                    bench.compare = Some(PathBuf::from(value));
+                }
+            }
+            "--base-url" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--base-url needs a URL.");
+                };
+                base_url = value.to_owned();
+            }
+            "--history" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--history needs a path.");
+                };
+                history = PathBuf::from(value);
+            }
+            "--title" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--title needs the puzzle's title.");
+                };
+                title = Some(value.to_owned());
+            }
+            "--log" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--log needs a filter, eg `info` or `aoc_2025=debug`.");
+                };
+                log = Some(value.to_owned());
+            }
+            "--trace" => {
+                let Some(value) = args.next() else {
+                    anyhow::bail!("--trace needs a path for the trace file.");
+                };
+                trace = Some(PathBuf::from(value));
+            }
+            flag if flag.starts_with("--") => anyhow::bail!("Unknown option {flag:?}."),
+            spec => day_specs.push(spec),
+        }
+    }
+
+    if command == Command::Submit {
+        let &[day, part] = day_specs.as_slice() else {
+            anyhow::bail!("submit needs a day and a part, eg `submit 3 2`.");
+        };
+        parts = match part {
+            "1" => Parts::One,
+            "2" => Parts::Two,
+            _ => anyhow::bail!("The part to submit must be 1 or 2."),
+        };
+        // A single day, not a range.
+        parse_day(day)?;
+        day_specs = vec![day];
+    }
+    if command == Command::New {
+        let &[day] = day_specs.as_slice() else {
+            anyhow::bail!("new needs the day to create, eg `new 10`.");
+        };
+        parse_day(day)?;
+    }
+    if matches!(command, Command::Verify | Command::Bench) && day_specs.is_empty() {
+        day_specs.push("all");
+    }
+    let year = match year {
+        Some(year) => year,
+        None => days::latest_year().context("No solvers are registered, give a --year.")?,
+    };
+    let days = parse_days(year, &day_specs)?;
+    if input != InputSource::Default && days.len() != 1 {
+        anyhow::bail!("--input can only be used when running a single day.");
+    }
+    if example && input != InputSource::Default {
+        anyhow::bail!("--example and --input can't be used together.");
+    }
+    if example && command == Command::Bench {
+        anyhow::bail!("--example can't be used with bench.");
+    }
+    if command == Command::Watch && !matches!(input, InputSource::Path(_)) {
+        anyhow::bail!("watch needs the file to watch, eg `watch 3 --input alice.txt`.");
+    }
+    if command == Command::Compare {
+        if days.len() != 1 {
+            anyhow::bail!("compare needs a single day, eg `compare 8`.");
+        }
+        if example || input != InputSource::Default {
+            anyhow::bail!("compare reads every named input, so can't use --input or --example.");
+        }
+    }
+    if raw {
+        if normalize.trailing_spaces {
+            anyhow::bail!("--raw and --trim-spaces can't be used together.");
+        }
+        normalize = Normalize::RAW;
+    }
+    Ok(Args {
+        command,
+        year,
+        days,
+        input,
+        normalize,
+        example,
+        parts,
+        format,
+        answers,
+        bench,
+        base_url,
+        history,
+        title,
+        jobs,
+        log,
+        trace,
+    })
+}
+
+/// Parse the days of `year` to run from the command-line arguments.
+///
+/// Each argument is a single day (`3`), an inclusive range of days (`1-9`),
+/// or `all` for every registered solver of `year`. Days are returned in the
+/// order given, with any repeats dropped.
+pub fn parse_days<S: AsRef<str>>(year: u16, args: &[S]) -> anyhow::Result<Vec<usize>> {
+    if args.is_empty() {
+        anyhow::bail!("You must give the day(s) to run, eg `1`, `1 3 5`, `1-9` or `all`.");
+    }
+    let mut selected = Vec::new();
+    for arg in args {
+        for day in parse_day_spec(year, arg.as_ref())? {
+            if !selected.contains(&day) {
+                selected.push(day);
+            }
+        }
+    }
+    Ok(selected)
+}
+
+fn parse_day_spec(year: u16, spec: &str) -> anyhow::Result<Vec<usize>> {
+    if spec == "all" {
+        let all: Vec<_> = days::implemented(year).collect();
+        if all.is_empty() {
+            anyhow::bail!("No days of {year} are implemented yet.");
+        }
+        return Ok(all);
+    }
+    if let Some((start, end)) = spec.split_once('-') {
+        let (start, end) = (parse_day(start)?, parse_day(end)?);
+        if start > end {
+            anyhow::bail!("Day range {spec:?} is backwards.");
+        }
+        return Ok((start..=end).collect());
+    }
+    Ok(vec![parse_day(spec)?])
+}
+
+fn parse_day(s: &str) -> anyhow::Result<usize> {
+    match s.parse::<usize>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        Ok(_) => anyhow::bail!("Day must be between 1 and 25."),
+        Err(_) => anyhow::bail!("{s:?} is not a valid day."),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{Args, Command, parse_args, parse_days};
+    use crate::{
+        Parts,
+        bench::BenchOptions,
+        input::{InputSource, Normalize},
+        report::Format,
+    };
+
+    #[test]
+    fn single_days_keep_order() -> anyhow::Result<()> {
+        assert_eq!(parse_days(2025, &["5", "1", "3"])?, vec![5, 1, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn ranges_are_inclusive() -> anyhow::Result<()> {
+        assert_eq!(parse_days(2025, &["1-4", "7"])?, vec![1, 2, 3, 4, 7]);
+        Ok(())
+    }
+
+    #[test]
+    fn repeats_are_dropped() -> anyhow::Result<()> {
+        assert_eq!(parse_days(2025, &["2-3", "1-3"])?, vec![2, 3, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn days_past_25_are_rejected() {
+        assert!(parse_days(2025, &["26"]).is_err());
+        assert!(parse_days(2025, &["1-100000"]).is_err());
+        assert!(parse_days(2025, &["25"]).is_ok());
+    }
+
+    #[test]
+    fn all_selects_every_solver() -> anyhow::Result<()> {
+        let all = parse_days(2025, &["all"])?;
+        assert_eq!(
+            all,
+            (1..=crate::days::implemented(2025).count()).collect::<Vec<_>>()
+        );
+        assert!(parse_days(1999, &["all"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn year_defaults_to_the_latest() -> anyhow::Result<()> {
+        assert_eq!(parse_args(&["1"])?.year, 2025);
+        let args = parse_args(&["--year", "2024", "3"])?;
+        assert_eq!((args.year, args.days), (2024, vec![3]));
+        assert!(parse_args(&["1", "--year"]).is_err());
+        assert!(parse_args(&["1", "--year", "next"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_specs_are_rejected() {
+        assert!(parse_days::<&str>(2025, &[]).is_err());
+        assert!(parse_days(2025, &["0"]).is_err());
+        assert!(parse_days(2025, &["3-1"]).is_err());
+        assert!(parse_days(2025, &["one"]).is_err());
+        assert!(parse_days(2025, &["1-"]).is_err());
+    }
+
+    #[test]
+    fn input_defaults_to_input_dir() -> anyhow::Result<()> {
+        let args = parse_args(&["1-3"])?;
+        assert_eq!(args.input, InputSource::Default);
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_input_path_and_stdin() -> anyhow::Result<()> {
+        let args = parse_args(&["--input", "alice.txt", "8"])?;
+        let expected = Args {
+            command: Command::Run,
+            year: 2025,
+            days: vec![8],
+            input: InputSource::Path(PathBuf::from("alice.txt")),
+            normalize: Normalize::default(),
+            example: false,
+            parts: Parts::Both,
+            format: Format::Text,
+            answers: PathBuf::from("answers.txt"),
+            bench: BenchOptions::default(),
+            base_url: "https://adventofcode.com".to_owned(),
+            history: PathBuf::from("input/submissions.txt"),
+            title: None,
+            jobs: 1,
+            log: None,
+            trace: None,
+        };
+        assert_eq!(args, expected);
+        assert_eq!(
+            parse_args(&["8", "--input", "-"])?.input,
+            InputSource::Stdin
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn normalization_can_be_changed() -> anyhow::Result<()> {
+        let args = parse_args(&["6", "--trim-spaces"])?;
+        assert!(args.normalize.trailing_spaces);
+        assert_eq!(parse_args(&["6", "--raw"])?.normalize, Normalize::RAW);
+        assert!(parse_args(&["6", "--raw", "--trim-spaces"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_input_needs_a_single_day() {
+        assert!(parse_args(&["--input", "alice.txt", "1-2"]).is_err());
+        assert!(parse_args(&["8", "--input"]).is_err());
+        assert!(parse_args(&["8", "--bogus"]).is_err());
+    }
+
+    #[test]
+    fn part_selection() -> anyhow::Result<()> {
+        assert_eq!(parse_args(&["all"])?.parts, Parts::Both);
+        assert_eq!(parse_args(&["--part", "1", "all"])?.parts, Parts::One);
+        assert_eq!(parse_args(&["all", "--part", "2"])?.parts, Parts::Two);
+        assert!(parse_args(&["all", "--part", "3"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn output_format() -> anyhow::Result<()> {
+        assert_eq!(parse_args(&["1"])?.format, Format::Text);
+        assert_eq!(parse_args(&["1", "--format", "json"])?.format, Format::Json);
+        assert_eq!(parse_args(&["--format", "csv", "1"])?.format, Format::Csv);
+        assert_eq!(
+            parse_args(&["1", "--format", "markdown"])?.format,
+            Format::Markdown
+        );
+        assert!(parse_args(&["1", "--format", "xml"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn verify_defaults_to_all_days() -> anyhow::Result<()> {
+        let args = parse_args(&["verify"])?;
+        assert_eq!(args.command, Command::Verify);
+        assert_eq!(args.days, parse_days(2025, &["all"])?);
+        let args = parse_args(&["verify", "2-3", "--answers", "mine.txt"])?;
+        assert_eq!(args.days, vec![2, 3]);
+        assert_eq!(args.answers, PathBuf::from("mine.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn bench_options() -> anyhow::Result<()> {
+        let args = parse_args(&["bench", "4", "8", "--runs", "50", "--save", "after.txt"])?;
+        assert_eq!(args.command, Command::Bench);
+        assert_eq!(args.days, vec![4, 8]);
+        let expected = BenchOptions {
+            warmup: 3,
+            runs: 50,
+            save: Some(PathBuf::from("after.txt")),
+            compare: None,
+        };
+        assert_eq!(args.bench, expected);
+        let args = parse_args(&["bench", "--warmup", "0", "--compare", "before.txt"])?;
+        assert_eq!(args.days, parse_days(2025, &["all"])?);
+        assert_eq!(args.bench.warmup, 0);
+        assert_eq!(args.bench.compare, Some(PathBuf::from("before.txt")));
+        assert!(parse_args(&["bench", "--runs", "many"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn example_inputs() -> anyhow::Result<()> {
+        assert!(!parse_args(&["7"])?.example);
+        let args = parse_args(&["7", "--example"])?;
+        assert!(args.example);
+        assert_eq!(args.input, InputSource::Default);
+        assert!(parse_args(&["verify", "--example"])?.example);
+        assert!(parse_args(&["7", "--example", "--input", "alice.txt"]).is_err());
+        assert!(parse_args(&["bench", "--example"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_days() -> anyhow::Result<()> {
+        let args = parse_args(&["fetch", "10"])?;
+        assert_eq!(args.command, Command::Fetch);
+        assert_eq!(args.days, vec![10]);
+        assert_eq!(args.base_url, "https://adventofcode.com");
+        let args = parse_args(&["fetch", "1-2", "--base-url", "http://localhost:8080"])?;
+        assert_eq!(args.days, vec![1, 2]);
+        assert_eq!(args.base_url, "http://localhost:8080");
+        assert!(parse_args(&["fetch"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn submit_day_and_part() -> anyhow::Result<()> {
+        let args = parse_args(&["submit", "9", "1"])?;
+        assert_eq!(args.command, Command::Submit);
+        assert_eq!(args.days, vec![9]);
+        assert_eq!(args.parts, Parts::One);
+        assert_eq!(args.history, PathBuf::from("input/submissions.txt"));
+        let args = parse_args(&["submit", "3", "2", "--history", "mine.txt"])?;
+        assert_eq!(args.parts, Parts::Two);
+        assert_eq!(args.history, PathBuf::from("mine.txt"));
+        assert!(parse_args(&["submit", "3"]).is_err());
+        assert!(parse_args(&["submit", "3", "both"]).is_err());
+        assert!(parse_args(&["submit", "1-2", "1"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn new_day() -> anyhow::Result<()> {
+        let args = parse_args(&["new", "10", "--title", "Factory"])?;
+        assert_eq!(args.command, Command::New);
+        assert_eq!(args.days, vec![10]);
+        assert_eq!(args.title.as_deref(), Some("Factory"));
+        assert_eq!(parse_args(&["new", "11"])?.title, None);
+        assert!(parse_args(&["new"]).is_err());
+        assert!(parse_args(&["new", "10-11"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn watch_needs_an_input_file() -> anyhow::Result<()> {
+        let args = parse_args(&["watch", "3", "--input", "alice.txt"])?;
+        assert_eq!(args.command, Command::Watch);
+        assert_eq!(args.days, vec![3]);
+        assert_eq!(args.input, InputSource::Path(PathBuf::from("alice.txt")));
+        assert!(parse_args(&["watch", "3"]).is_err());
+        assert!(parse_args(&["watch", "3", "--input", "-"]).is_err());
+        assert!(parse_args(&["watch", "3-4", "--input", "alice.txt"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn compare_needs_a_single_day() -> anyhow::Result<()> {
+        let args = parse_args(&["compare", "8", "--answers", "team.txt"])?;
+        assert_eq!(args.command, Command::Compare);
+        assert_eq!(args.days, vec![8]);
+        assert_eq!(args.answers, PathBuf::from("team.txt"));
+        assert!(parse_args(&["compare", "8-9"]).is_err());
+        assert!(parse_args(&["compare", "8", "--input", "alice.txt"]).is_err());
+        assert!(parse_args(&["compare", "8", "--example"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tracing_options() -> anyhow::Result<()> {
+        let args = parse_args(&["all", "--log", "aoc_2025=debug", "--trace", "run.json"])?;
+        assert_eq!(args.log.as_deref(), Some("aoc_2025=debug"));
+        assert_eq!(args.trace, Some(PathBuf::from("run.json")));
+        assert!(parse_args(&["all", "--trace"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn jobs() -> anyhow::Result<()> {
+        assert_eq!(parse_args(&["all"])?.jobs, 1);
+        assert_eq!(parse_args(&["all", "--jobs", "4"])?.jobs, 4);
+        assert!(parse_args(&["all", "--jobs", "0"])?.jobs >= 1);
+        assert!(parse_args(&["all", "--jobs", "-1"]).is_err());
+        Ok(())
+    }
+}
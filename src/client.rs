@@ -0,0 +1,248 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::input;
+
+/// Environment variable holding the `session` cookie for adventofcode.com.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Where puzzle inputs are downloaded from unless `--base-url` says otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// A client for the Advent of Code website, authenticated by session cookie.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            // Statuses are reported with more helpful errors by `read_body`.
+            .http_status_as_error(false)
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            agent,
+        }
+    }
+
+    /// A client for `base_url` using the session token in [`SESSION_VAR`].
+    pub fn from_env(base_url: &str) -> Self {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty());
+        Self::new(base_url, session)
+    }
+
+    fn session(&self) -> anyhow::Result<&str> {
+        match &self.session {
+            Some(session) => Ok(session.trim()),
+            None => anyhow::bail!("Set {SESSION_VAR} to your adventofcode.com session cookie."),
+        }
+    }
+
+    /// Download the puzzle input for `year`'s `day`.
+    pub fn get_input(&self, year: u16, day: usize) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session()?))
+            .call();
+        read_body(response, day, &url)
+    }
+
+    /// Post `answer` for `year`'s `day`'s `part`, returning the page sent back.
+    pub fn post_answer(
+        &self,
+        year: u16,
+        day: usize,
+        part: u8,
+        answer: &str,
+    ) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session()?))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)]);
+        read_body(response, day, &url)
+    }
+}
+
+fn read_body(
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    day: usize,
+    url: &str,
+) -> anyhow::Result<String> {
+    let mut response = response.with_context(|| format!("Failed to request {url}"))?;
+    match response.status().as_u16() {
+        200 => {}
+        400 | 401 => anyhow::bail!("The session token in {SESSION_VAR} was rejected."),
+        404 => anyhow::bail!("Day {day} isn't unlocked yet."),
+        status => anyhow::bail!("Got HTTP {status} from {url}"),
+    }
+    response
+        .body_mut()
+        .read_to_string()
+        .with_context(|| format!("Failed to read the response from {url}"))
+}
+
+/// The outcome of [`fetch_input`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, so nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Download the input for `year`'s `day` into `dir`, unless it's already there.
+///
+/// The file is named as [`input::default_path`] expects, and is written to a
+/// temporary file first so a failed download never leaves a partial input.
+pub fn fetch_input(client: &Client, year: u16, day: usize, dir: &Path) -> anyhow::Result<Fetched> {
+    let path = input::path_in(dir, year, day);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client.get_input(year, day)?;
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let partial = path.with_extension("txt.part");
+    std::fs::write(&partial, input)
+        .and_then(|()| std::fs::rename(&partial, &path))
+        .with_context(|| format!("Failed to save the input to {}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Helpers for testing against a local stand-in for the website.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+    };
+
+    /// A request received by the stub server.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub request_line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find_map(|header| {
+                let (key, value) = header.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then_some(value.trim())
+            })
+        }
+    }
+
+    /// Serve each request with `respond`, returning the server's base URL and
+    /// a log of the requests it has received.
+    pub fn serve(
+        respond: impl Fn(&Request) -> (u16, String) + Send + 'static,
+    ) -> (String, Arc<Mutex<Vec<Request>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let requests = Arc::clone(&log);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    headers.push(line.to_owned());
+                }
+                let mut request = Request {
+                    request_line: request_line.trim_end().to_owned(),
+                    headers,
+                    body: String::new(),
+                };
+                let length = request
+                    .header("Content-Length")
+                    .map_or(0, |n| n.parse().unwrap());
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                request.body = String::from_utf8(body).unwrap();
+
+                let (status, body) = respond(&request);
+                requests.lock().unwrap().push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, log)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Client, Fetched, fetch_input, stub};
+    use crate::util::temp_dir;
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() -> anyhow::Result<()> {
+        let (url, requests) = stub::serve(|_| (200, "1,2\n3,4\n".to_owned()));
+        let client = Client::new(&url, Some("abc123".to_owned()));
+        let dir = temp_dir("fetch-cache");
+        let path = dir.join("2024-09.txt");
+
+        assert_eq!(
+            fetch_input(&client, 2024, 9, &dir)?,
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(std::fs::read_to_string(&path)?, "1,2\n3,4\n");
+        assert_eq!(fetch_input(&client, 2024, 9, &dir)?, Fetched::Cached(path));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].request_line, "GET /2024/day/9/input HTTP/1.1");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+        Ok(())
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (url, _) = stub::serve(|_| (404, "Not found".to_owned()));
+        let client = Client::new(&url, Some("abc123".to_owned()));
+        let dir = temp_dir("fetch-locked");
+        let err = fetch_input(&client, 2025, 12, &dir).unwrap_err();
+        assert_eq!(err.to_string(), "Day 12 isn't unlocked yet.");
+        assert!(std::fs::read_dir(&dir).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn session_is_required_to_download() {
+        let (url, requests) = stub::serve(|_| (200, String::new()));
+        let client = Client::new(&url, None);
+        let dir = temp_dir("fetch-no-session");
+        assert!(fetch_input(&client, 2025, 1, &dir).is_err());
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
@@ -8,12 +8,51 @@ use nom::{
     sequence::terminated,
 };
 
-use crate::util::Answer;
+use crate::{
+    Value,
+    parse::{ParseError, parse_all},
+    solver::{Example, Solver},
+};
+
+pub struct Day01;
+
+const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+/// `times_at_zero` counts both parts in one pass, but each part makes its
+/// own pass so it can be run and timed alone.
+impl Solver for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 1;
+    const TITLE: &'static str = "Secret Entrance";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("3"),
+        part_two: Some("6"),
+    }];
+
+    type Parsed = Vec<i16>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
 
-pub fn solve(input: &str) -> anyhow::Result<String> {
-    let rotations = parse_input(input).expect("Failed to parse real input.");
-    let (p1, p2) = times_at_zero(50, &rotations);
-    Answer::first(1, p1).second(p2).report()
+    fn part_one(&self, rotations: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        Ok(times_at_zero(50, rotations).0)
+    }
+
+    fn part_two(&self, rotations: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        Some(Ok(times_at_zero(50, rotations).1))
+    }
 }
 
 fn parse_line(line: &str) -> IResult<&str, i16> {
@@ -23,13 +62,11 @@ fn parse_line(line: &str) -> IResult<&str, i16> {
     Ok((leftover, rotation))
 }
 
-fn parse_input(s: &str) -> anyhow::Result<Vec<i16>> {
-    let mut full = terminated(separated_list1(newline, parse_line), opt(newline));
-    let (leftover, rotations) = full.parse(s).map_err(|e| e.to_owned())?;
-    if !leftover.is_empty() {
-        anyhow::bail!("Did not parse full input, leftover: {leftover:?}");
-    }
-    Ok(rotations)
+fn parse_input(s: &str) -> Result<Vec<i16>, ParseError> {
+    parse_all(
+        s,
+        terminated(separated_list1(newline, parse_line), opt(newline)),
+    )
 }
 
 fn times_at_zero(start: i16, rotations: &[i16]) -> (i16, i16) {
@@ -63,19 +100,8 @@ fn times_at_zero(start: i16, rotations: &[i16]) -> (i16, i16) {
 
 #[cfg(test)]
 mod test {
-    use super::{parse_input, times_at_zero};
-
-    static TEST_INPUT: &str = "\
-L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
+    use super::{EXAMPLE, parse_input, times_at_zero};
+    use crate::answers::known_answer;
 
     static ENTIRELY_IN_RANGE: &str = "\
 L50
@@ -84,21 +110,21 @@ L10";
 
     #[test]
     fn parse_test_input() {
-        let rotations = parse_input(TEST_INPUT).expect("Test input failed to parse");
+        let rotations = parse_input(EXAMPLE).expect("Test input failed to parse");
         let expected = vec![-68, -30, 48, -5, 60, -55, -1, -99, 14, -82];
         assert_eq!(rotations, expected);
     }
 
     #[test]
     pub fn test_times_at_zero_part_one() {
-        let rotations = parse_input(TEST_INPUT).expect("Test input failed to parse");
+        let rotations = parse_input(EXAMPLE).expect("Test input failed to parse");
         let (p1, _) = times_at_zero(50, &rotations);
         assert_eq!(p1, 3);
     }
 
     #[test]
     pub fn test_times_at_zero_part_two() {
-        let rotations = parse_input(TEST_INPUT).expect("Test input failed to parse");
+        let rotations = parse_input(EXAMPLE).expect("Test input failed to parse");
         let (_, p2) = times_at_zero(50, &rotations);
         assert_eq!(p2, 6);
     }
@@ -119,9 +145,12 @@ L10";
 
     #[test]
     pub fn test_known_answers() {
-        let input = include_str!("../../input/2025-01.txt");
-        let rotations = parse_input(input).expect("Real input failed to parse");
-        let result = times_at_zero(50, &rotations);
-        assert_eq!(result, (999, 6099));
+        let Some(input) = crate::days::test_input(2025, 1) else {
+            return;
+        };
+        let rotations = parse_input(&input).expect("Real input failed to parse");
+        let (p1, p2) = times_at_zero(50, &rotations);
+        assert_eq!(p1.to_string(), known_answer(2025, 1, 1));
+        assert_eq!(p2.to_string(), known_answer(2025, 1, 2));
     }
 }
@@ -1,7 +1,6 @@
 use std::{collections::HashSet, ops::RangeInclusive};
 
 use nom::{
-    Parser,
     bytes::complete::tag,
     character::complete::{newline, u64 as parse_u64},
     combinator::opt,
@@ -10,17 +9,43 @@ use nom::{
 };
 
 use crate::NumUtil;
-use crate::util::Answer;
+use crate::parse::{ParseError, parse_all};
+use crate::{
+    Value,
+    solver::{Example, Solver},
+};
+
+pub struct Day02;
+
+const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+impl Solver for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 2;
+    const TITLE: &'static str = "Gift Shop";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("1227775554"),
+        part_two: Some("4174379265"),
+    }];
+
+    type Parsed = Vec<RangeInclusive<u64>>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(&self, ranges: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        Ok(sum_invalid(ranges, part_one_sum_all_multiples))
+    }
 
-pub fn solve(input: &str) -> anyhow::Result<String> {
-    let ranges = parse_input(input)?;
-    let p1 = sum_invalid(&ranges, part_one_sum_all_multiples);
-    let p2 = sum_invalid(&ranges, part_two_sum_all_multiples);
-    Answer::first(2, p1).second(p2).report()
+    fn part_two(&self, ranges: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        Some(Ok(sum_invalid(ranges, part_two_sum_all_multiples)))
+    }
 }
 
-fn parse_input(s: &str) -> anyhow::Result<Vec<RangeInclusive<u64>>> {
-    let mut parser = terminated(
+fn parse_input(s: &str) -> Result<Vec<RangeInclusive<u64>>, ParseError> {
+    let parser = terminated(
         separated_list1(
             tag(","),
             separated_pair(
@@ -31,10 +56,7 @@ fn parse_input(s: &str) -> anyhow::Result<Vec<RangeInclusive<u64>>> {
         ),
         opt(newline),
     );
-    let (leftover, pairs) = parser.parse(s).map_err(|e| e.to_owned())?;
-    if !leftover.is_empty() {
-        anyhow::bail!("Did not parse complete input, leftover: {leftover:?}");
-    }
+    let pairs = parse_all(s, parser)?;
     let ranges = pairs.into_iter().map(|(start, end)| start..=end).collect();
     Ok(ranges)
 }
@@ -118,13 +140,14 @@ fn part_two_sum_all_multiples(range: &RangeInclusive<u64>) -> u64 {
 
 #[cfg(test)]
 mod test {
-    use super::{parse_input, part_one_sum_all_multiples, part_two_sum_all_multiples, sum_invalid};
-
-    static TEST_INPUT: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+    use super::{
+        EXAMPLE, parse_input, part_one_sum_all_multiples, part_two_sum_all_multiples, sum_invalid,
+    };
+    use crate::answers::known_answer;
 
     #[test]
     fn parse_test_input() -> anyhow::Result<()> {
-        let res = parse_input(TEST_INPUT)?;
+        let res = parse_input(EXAMPLE)?;
         let expected = vec![
             (11..=22),
             (95..=115),
@@ -144,7 +167,7 @@ mod test {
 
     #[test]
     pub fn part_one_test_input() -> anyhow::Result<()> {
-        let ranges = parse_input(TEST_INPUT)?;
+        let ranges = parse_input(EXAMPLE)?;
         let result = sum_invalid(&ranges, part_one_sum_all_multiples);
         assert_eq!(result, 1227775554);
         Ok(())
@@ -152,15 +175,18 @@ mod test {
 
     #[test]
     pub fn part_one_known_answer() -> anyhow::Result<()> {
-        let ranges = parse_input(crate::days::get_input(2).unwrap())?;
+        let Some(input) = crate::days::test_input(2025, 2) else {
+            return Ok(());
+        };
+        let ranges = parse_input(&input)?;
         let result = sum_invalid(&ranges, part_one_sum_all_multiples);
-        assert_eq!(result, 18595663903);
+        assert_eq!(result.to_string(), known_answer(2025, 2, 1));
         Ok(())
     }
 
     #[test]
     pub fn part_two_test_input() -> anyhow::Result<()> {
-        let ranges = parse_input(TEST_INPUT)?;
+        let ranges = parse_input(EXAMPLE)?;
         let result = sum_invalid(&ranges, part_two_sum_all_multiples);
         assert_eq!(result, 4174379265);
         Ok(())
@@ -168,9 +194,12 @@ mod test {
 
     #[test]
     pub fn part_two_known_answer() -> anyhow::Result<()> {
-        let ranges = parse_input(crate::days::get_input(2).unwrap())?;
+        let Some(input) = crate::days::test_input(2025, 2) else {
+            return Ok(());
+        };
+        let ranges = parse_input(&input)?;
         let result = sum_invalid(&ranges, part_two_sum_all_multiples);
-        assert_eq!(result, 19058204438);
+        assert_eq!(result.to_string(), known_answer(2025, 2, 2));
         Ok(())
     }
 }
@@ -0,0 +1,146 @@
+use crate::{
+    Value,
+    parse::ParseError,
+    solver::{Example, Solver},
+};
+
+pub struct Day03;
+
+const EXAMPLE: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+impl Solver for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 3;
+    const TITLE: &'static str = "Lobby";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("357"),
+        part_two: Some("3121910778619"),
+    }];
+
+    type Parsed = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(&self, banks: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        check_bank_sizes(2, banks)?;
+        Ok(solve_banks(2, banks))
+    }
+
+    fn part_two(&self, banks: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        Some(check_bank_sizes(12, banks).map(|()| solve_banks(12, banks)))
+    }
+}
+
+fn parse_input(s: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    s.trim_end()
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(idx, c)| match c.to_digit(10) {
+                    Some(digit) => Ok(digit as u8),
+                    None => Err(ParseError::at(s, &line[idx..], format!("{c:?} is not a digit"))),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn check_bank_sizes(n_batteries: usize, banks: &[Vec<u8>]) -> anyhow::Result<()> {
+    if let Some(idx) = banks.iter().position(|bank| bank.len() < n_batteries) {
+        anyhow::bail!("Bank {} has fewer than {n_batteries} batteries.", idx + 1);
+    }
+    Ok(())
+}
+
+/// Sum the maximum n-digit joltage per bank.
+fn solve_banks(n_batteries: usize, banks: &[Vec<u8>]) -> u64 {
+    banks.iter().map(|b| max_for_bank(n_batteries, b)).sum()
+}
+
+fn slice_earliest_max(s: &[u8]) -> (usize, &u8) {
+    s.iter()
+        .enumerate()
+        // idx subtracted from usize::MAX to prioritise earlier digits.
+        .max_by_key(|(idx, value)| (*value, usize::MAX - idx))
+        .expect("Tried to find max digit in empty slice")
+}
+
+/// Finds the maximum n-digit joltage for a given bank.
+///
+/// Repeatedly Chooses the maximum digit from the largest view into the bank that
+/// allows for the rest of the n-digit number to be completed.
+fn max_for_bank(n_batteries: usize, mut bank: &[u8]) -> u64 {
+    let mut result = 0;
+    for batteries_remaining in (0..n_batteries).rev() {
+        let (idx, digit) = slice_earliest_max(&bank[..bank.len() - batteries_remaining]);
+        result = result * 10 + *digit as u64;
+        bank = &bank[idx + 1..];
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EXAMPLE, parse_input, solve_banks};
+    use crate::answers::known_answer;
+
+    #[test]
+    fn parse_test_input() {
+        let banks = parse_input(EXAMPLE).unwrap();
+        let expected = vec![
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+            vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+            vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+            vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+        ];
+        assert_eq!(banks, expected);
+    }
+
+    #[test]
+    pub fn part_one_test_input() {
+        let banks = parse_input(EXAMPLE).unwrap();
+        let result = solve_banks(2, &banks);
+        assert_eq!(result, 357);
+    }
+
+    #[test]
+    pub fn part_one_known_answer() {
+        let Some(input) = crate::days::test_input(2025, 3) else {
+            return;
+        };
+        let banks = parse_input(&input).unwrap();
+        let result = solve_banks(2, &banks);
+        assert_eq!(result.to_string(), known_answer(2025, 3, 1));
+    }
+
+    #[test]
+    pub fn part_two_test_input() {
+        let banks = parse_input(EXAMPLE).unwrap();
+        let result = solve_banks(12, &banks);
+        assert_eq!(result, 3121910778619);
+    }
+
+    #[test]
+    pub fn part_two_known_answer() {
+        let Some(input) = crate::days::test_input(2025, 3) else {
+            return;
+        };
+        let banks = parse_input(&input).unwrap();
+        let result = solve_banks(12, &banks);
+        assert_eq!(result.to_string(), known_answer(2025, 3, 2));
+    }
+
+    #[test]
+    fn bad_input_is_reported() {
+        let err = parse_input("12\n3x4\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert!(super::check_bank_sizes(3, &[vec![1, 2, 3], vec![1, 2]]).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use crate::{
+    Grid, Value,
+    parse::ParseError,
+    solver::{Example, Solver},
+};
+
+pub struct Day04;
+
+const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+impl Solver for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 4;
+    const TITLE: &'static str = "Printing Department";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("13"),
+        part_two: Some("43"),
+    }];
+
+    type Parsed = Floor;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, floor: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        Ok(solve_part_one(floor))
+    }
+
+    fn part_two(&self, floor: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        Some(Ok(solve_part_two(floor)))
+    }
+}
+
+fn solve_part_one(floor: &Floor) -> usize {
+    floor.pending_removal.len()
+}
+
+fn solve_part_two(floor: &mut Floor) -> usize {
+    floor.remove_accessible()
+}
+
+/// The rolls of paper on the floor, and those that can be reached.
+pub struct Floor {
+    filled: Grid<bool>,
+    pending_removal: VecDeque<(usize, usize)>,
+    queued_for_removal: Grid<bool>,
+}
+
+impl Floor {
+    fn new(filled: Grid<bool>) -> Self {
+        let mut floor = Self {
+            // In part 2 this grows up to 1819; 2048 * (8 * 2) == 32K.
+            pending_removal: VecDeque::with_capacity(2048),
+            queued_for_removal: Grid::new(filled.width(), filled.height(), false),
+            filled,
+        };
+        // Find all the initially accessible rolls.
+        let rolls: Vec<_> = floor
+            .filled
+            .cells()
+            .filter(|&(_, &filled)| filled)
+            .map(|(location, _)| location)
+            .collect();
+        for location in rolls {
+            if floor.is_accessible(location) {
+                floor.queue_for_removal(location);
+            }
+        }
+        floor
+    }
+
+    fn filled_neighbours(&self, location: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.filled
+            .neighbours8(location)
+            .filter(|&neighbour| self.filled[neighbour])
+    }
+
+    fn queue_for_removal(&mut self, location: (usize, usize)) {
+        self.pending_removal.push_back(location);
+        self.queued_for_removal[location] = true;
+    }
+
+    fn is_accessible(&self, location: (usize, usize)) -> bool {
+        self.filled_neighbours(location).count() < 4
+    }
+
+    fn remove_accessible(&mut self) -> usize {
+        let mut removed = 0;
+        while let Some(roll) = self.pending_removal.pop_front() {
+            self.filled[roll] = false;
+            removed += 1;
+            // Maybe some neighbours can now be removed.
+            for neighbour in self.filled.neighbours8(roll) {
+                // Check we haven't already put this neighbour in the queue.
+                if self.filled[neighbour]
+                    && !self.queued_for_removal[neighbour]
+                    && self.is_accessible(neighbour)
+                {
+                    self.queue_for_removal(neighbour);
+                }
+            }
+        }
+        removed
+    }
+}
+
+impl FromStr for Floor {
+    type Err = ParseError;
+
+    /// Parse a grid of `@` rolls and `.` spaces.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let filled = Grid::parse(value, |c| match c {
+            '@' => Ok(true),
+            '.' => Ok(false),
+            c => Err(format!("expected '@' or '.', found {c:?}")),
+        })?;
+        Ok(Floor::new(filled))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EXAMPLE, Floor};
+    use crate::answers::known_answer;
+
+    #[test]
+    fn parse_test_input() {
+        let floor = EXAMPLE.parse::<Floor>().unwrap();
+        assert!(floor.filled[(0, 2)]);
+        assert!(floor.filled[(0, 8)]);
+        assert!(floor.filled[(1, 0)]);
+        assert!(floor.filled[(1, 1)]);
+        assert!(floor.filled[(1, 9)]);
+        assert!(floor.filled[(9, 0)]);
+        assert!(floor.filled[(9, 8)]);
+    }
+
+    #[test]
+    fn test_input_neighbours() {
+        let floor = EXAMPLE.parse::<Floor>().unwrap();
+        let mut neighbours: Vec<_> = floor.filled_neighbours((4, 9)).collect();
+        neighbours.sort();
+        assert_eq!(neighbours, [(3, 8), (4, 8), (5, 9)]);
+    }
+
+    #[test]
+    fn part_one_test_input() {
+        let floor = EXAMPLE.parse::<Floor>().unwrap();
+        let result = super::solve_part_one(&floor);
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn part_one_known_answer() {
+        let Some(input) = crate::days::test_input(2025, 4) else {
+            return;
+        };
+        let floor = input.parse::<Floor>().unwrap();
+        let result = super::solve_part_one(&floor);
+        assert_eq!(result.to_string(), known_answer(2025, 4, 1));
+    }
+
+    #[test]
+    fn part_two_test_input() {
+        let mut floor = EXAMPLE.parse::<Floor>().unwrap();
+        let result = super::solve_part_two(&mut floor);
+        assert_eq!(result, 43);
+    }
+
+    #[test]
+    fn part_two_known_answer() {
+        let Some(input) = crate::days::test_input(2025, 4) else {
+            return;
+        };
+        let mut floor = input.parse::<Floor>().unwrap();
+        let result = super::solve_part_two(&mut floor);
+        assert_eq!(result.to_string(), known_answer(2025, 4, 2));
+    }
+
+    #[test]
+    fn bad_grids_are_reported() {
+        let err = |input: &str| input.parse::<Floor>().err().unwrap();
+        assert_eq!((err("..\n.x\n").line, err("..\n.x\n").column), (2, 2));
+        assert_eq!((err("..\n.\n").line, err("..\n.\n").column), (2, 1));
+        assert_eq!(err("").line, 1);
+    }
+}
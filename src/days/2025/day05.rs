@@ -0,0 +1,203 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use nom::{
+    IResult, Parser,
+    bytes::complete::tag,
+    character::complete::{newline, u64},
+    combinator::{consumed, opt},
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+
+use crate::{
+    IntervalSet, Value,
+    parse::{ParseError, parse_all},
+    solver::{Example, Solver},
+};
+
+pub struct Day05;
+
+const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+impl Solver for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 5;
+    const TITLE: &'static str = "Cafeteria";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("3"),
+        part_two: Some("14"),
+    }];
+
+    type Parsed = Database;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, database: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        Ok(database.count_available_fresh())
+    }
+
+    fn part_two(&self, database: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        Some(Ok(database.count_all_fresh()))
+    }
+}
+
+pub struct Database {
+    fresh: IntervalSet<u64>,
+    available_ingredients: Vec<u64>,
+}
+
+impl Database {
+    fn is_fresh(&self, ingredient: &u64) -> bool {
+        self.fresh.contains(ingredient)
+    }
+
+    fn count_available_fresh(&self) -> usize {
+        self.available_ingredients
+            .iter()
+            .filter(|i| self.is_fresh(i))
+            .count()
+    }
+
+    fn count_all_fresh(&self) -> u128 {
+        self.fresh.len()
+    }
+}
+
+impl FromStr for Database {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_input(s)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Database, ParseError> {
+    fn range(input: &str) -> IResult<&str, (&str, RangeInclusive<u64>)> {
+        let (remaining, (text, res)) = consumed(separated_pair(u64, tag("-"), u64)).parse(input)?;
+        Ok((remaining, (text, res.0..=res.1)))
+    }
+    fn range_list(input: &str) -> IResult<&str, Vec<(&str, RangeInclusive<u64>)>> {
+        separated_list1(newline, range).parse(input)
+    }
+    fn double_newline(input: &str) -> IResult<&str, &str> {
+        tag("\n\n").parse(input)
+    }
+    fn ingredients_list(input: &str) -> IResult<&str, Vec<u64>> {
+        separated_list1(newline, u64).parse(input)
+    }
+    fn trailing_newline(input: &str) -> IResult<&str, Option<char>> {
+        opt(newline).parse(input)
+    }
+
+    let (ranges, _, ingredients, _) = parse_all(
+        input,
+        (
+            range_list,
+            double_newline,
+            ingredients_list,
+            trailing_newline,
+        ),
+    )?;
+    let fresh = ranges
+        .into_iter()
+        .map(|(text, range)| {
+            if range.is_empty() {
+                Err(ParseError::at(input, text, "range starts after it ends"))
+            } else {
+                Ok(range)
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Database {
+        fresh,
+        available_ingredients: ingredients,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Database, EXAMPLE};
+    use crate::answers::known_answer;
+
+    #[test]
+    fn parse_test_input() -> anyhow::Result<()> {
+        let database: Database = EXAMPLE.parse()?;
+        // Ranges are merged after parsing.
+        let expected_ranges = vec![3..=5, 10..=20];
+        let expected_available = vec![1, 5, 8, 11, 17, 32];
+        assert_eq!(database.fresh.ranges(), expected_ranges);
+        assert_eq!(database.available_ingredients, expected_available);
+        Ok(())
+    }
+
+    #[test]
+    fn part_one_test_freshness() -> anyhow::Result<()> {
+        let database: Database = EXAMPLE.parse()?;
+        assert!(!database.is_fresh(&32));
+        assert!(database.is_fresh(&5));
+        Ok(())
+    }
+
+    #[test]
+    fn part_one_count_fresh() -> anyhow::Result<()> {
+        let database: Database = EXAMPLE.parse()?;
+        let n_fresh = database.count_available_fresh();
+        assert_eq!(3, n_fresh);
+        Ok(())
+    }
+
+    #[test]
+    pub fn part_one_known_answer() -> anyhow::Result<()> {
+        let Some(input) = crate::days::test_input(2025, 5) else {
+            return Ok(());
+        };
+        let database: Database = input.parse()?;
+        let n_fresh = database.count_available_fresh();
+        assert_eq!(n_fresh.to_string(), known_answer(2025, 5, 1));
+        Ok(())
+    }
+
+    #[test]
+    pub fn part_two_count_all_fresh() -> anyhow::Result<()> {
+        let database: Database = EXAMPLE.parse()?;
+        let n_all_fresh = database.count_all_fresh();
+        assert_eq!(n_all_fresh, 14);
+        Ok(())
+    }
+
+    #[test]
+    pub fn part_two_known_answer() -> anyhow::Result<()> {
+        let Some(input) = crate::days::test_input(2025, 5) else {
+            return Ok(());
+        };
+        let database: Database = input.parse()?;
+        let n_fresh = database.count_all_fresh();
+        assert_eq!(n_fresh.to_string(), known_answer(2025, 5, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn bad_input_is_reported() {
+        let err = "3-5\n10-14\n1\n".parse::<Database>().err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = "3-5\n14-10\n\n1\n".parse::<Database>().err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "range starts after it ends");
+    }
+}
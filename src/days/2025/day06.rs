@@ -0,0 +1,349 @@
+use crate::{
+    Value,
+    solver::{Example, Solver},
+};
+
+pub struct Day06;
+
+const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314 \n*   +   *   +  \n";
+
+impl Solver for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 6;
+    const TITLE: &'static str = "Trash Compactor";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("4277556"),
+        part_two: Some("3263827"),
+    }];
+    // Part two reads numbers down the columns, so the spaces line them up.
+    const SIGNIFICANT_SPACES: bool = true;
+
+    /// The two parts read the worksheet differently, so each does its own
+    /// parsing from the raw text.
+    type Parsed = String;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(&self, worksheet: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        Ok(sum_calculated_groups(&part_one::parse_input(worksheet)?))
+    }
+
+    fn part_two(&self, worksheet: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        Some(
+            part_two::parse_input(worksheet)
+                .map(|groups| sum_calculated_groups(&groups))
+                .map_err(Into::into),
+        )
+    }
+}
+
+fn sum_calculated_groups(groups: &[Group]) -> u64 {
+    groups.iter().map(Group::apply).sum()
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Op {
+    Add,
+    Multiply,
+}
+
+impl Op {
+    fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Op::Add => a + b,
+            Op::Multiply => a * b,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Group {
+    numbers: Vec<u64>,
+    operator: Op,
+}
+
+impl From<(Vec<u64>, Op)> for Group {
+    fn from((numbers, operator): (Vec<u64>, Op)) -> Self {
+        Self { numbers, operator }
+    }
+}
+
+impl Group {
+    fn apply(&self) -> u64 {
+        self.numbers
+            .iter()
+            .copied()
+            .reduce(|a, b| self.operator.apply(a, b))
+            .unwrap()
+    }
+}
+
+mod part_one {
+    use nom::{
+        IResult, Parser,
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{multispace0, newline, space0, space1, u64},
+        combinator::value,
+        multi::separated_list1,
+        sequence::{separated_pair, terminated},
+    };
+
+    use crate::{
+        Grid,
+        parse::{ParseError, parse_all},
+    };
+
+    use super::{Group, Op};
+
+    pub(super) fn parse_input(input: &str) -> Result<Vec<Group>, ParseError> {
+        let (number_rows, operators) = parse_all(input, terminated(rows, multispace0))?;
+        // Every row needs a number for each operator, to be transposed.
+        for (row, line) in number_rows.iter().zip(input.lines()) {
+            if row.len() != operators.len() {
+                let message = format!("expected {} numbers, found {}", operators.len(), row.len());
+                return Err(ParseError::at(input, line, message));
+            }
+        }
+        let numbers = Grid::from_rows(number_rows).expect("rows were checked above");
+        // Pair each column of numbers with its operator
+        let columns = numbers
+            .columns()
+            .map(|column| column.copied().collect())
+            .zip(operators)
+            .map(Group::from)
+            .collect();
+        Ok(columns)
+    }
+
+    fn rows(input: &str) -> IResult<&str, (Vec<Vec<u64>>, Vec<Op>)> {
+        fn numbers(input: &str) -> IResult<&str, Vec<u64>> {
+            let (remaining, _) = space0(input)?;
+            let (remaining, numbers) = separated_list1(space1, u64).parse(remaining)?;
+            let (remaining, _) = space0(remaining)?;
+            Ok((remaining, numbers))
+        }
+        fn number_rows(input: &str) -> IResult<&str, Vec<Vec<u64>>> {
+            separated_list1(newline, numbers).parse(input)
+        }
+        fn operator(input: &str) -> IResult<&str, Op> {
+            alt((value(Op::Add, tag("+")), value(Op::Multiply, tag("*")))).parse(input)
+        }
+        fn operator_row(input: &str) -> IResult<&str, Vec<Op>> {
+            separated_list1(space1, operator).parse(input)
+        }
+        separated_pair(number_rows, newline, operator_row).parse(input)
+    }
+}
+
+mod part_two {
+    use crate::{Grid, parse::ParseError};
+
+    use super::{Group, Op};
+
+    pub(super) fn parse_input(input: &str) -> Result<Vec<Group>, ParseError> {
+        let Some((number_lines, op_line)) = input.trim_end().rsplit_once('\n') else {
+            return Err(ParseError::at_offset(
+                input,
+                input.len(),
+                "expected a row of operators after the numbers",
+            ));
+        };
+        // Spaces are significant, so pad short rows rather than trimming.
+        let width = number_lines.lines().map(|line| line.chars().count()).max();
+        let rows = number_lines
+            .lines()
+            .map(|line| {
+                let mut row: Vec<char> = line.chars().collect();
+                row.resize(width.unwrap_or(0), ' ');
+                row
+            })
+            .collect();
+        let digits = Grid::from_rows(rows).expect("rows were padded to the same width");
+        let mut columns = digits.columns().enumerate().peekable();
+        let mut number_groups = Vec::new();
+        while columns.peek().is_some() {
+            let mut current_group = Vec::with_capacity(4);
+            for (column_idx, column) in columns.by_ref() {
+                let column: Vec<char> = column.copied().collect();
+                if column.iter().all(|&c| c == ' ') {
+                    break;
+                }
+                current_group.push(parse_column(input, column_idx, column)?);
+            }
+            // Runs of blank columns, like padding at either end, only separate
+            // the groups, and a missing group shows up in the operator count.
+            if !current_group.is_empty() {
+                number_groups.push(current_group);
+            }
+        }
+
+        let operators = parse_operator_line(input, op_line)?;
+        if operators.len() != number_groups.len() {
+            let message = format!(
+                "expected {} operators, found {}",
+                number_groups.len(),
+                operators.len()
+            );
+            return Err(ParseError::at(input, op_line, message));
+        }
+        let groups = number_groups
+            .into_iter()
+            .zip(operators)
+            .map(Group::from)
+            .collect();
+        Ok(groups)
+    }
+
+    /// Read the number written down column `column_idx` of `input`, whose
+    /// digits may have spaces above or below them.
+    fn parse_column(input: &str, column_idx: usize, column: Vec<char>) -> Result<u64, ParseError> {
+        let mut total = 0;
+        for (row, c) in column.into_iter().enumerate() {
+            match c {
+                ' ' => {}
+                '0'..='9' => total = (total * 10) + u64::from(c as u8 - b'0'),
+                c => {
+                    let message = format!("expected a digit or ' ', found {c:?}");
+                    return Err(ParseError::at_cell(input, (row, column_idx), message));
+                }
+            }
+        }
+        Ok(total)
+    }
+
+    fn parse_operator_line(input: &str, line: &str) -> Result<Vec<Op>, ParseError> {
+        line.char_indices()
+            .filter(|&(_, c)| c != ' ')
+            .map(|(idx, c)| match c {
+                '+' => Ok(Op::Add),
+                '*' => Ok(Op::Multiply),
+                c => Err(ParseError::at(
+                    input,
+                    &line[idx..],
+                    format!("expected '+' or '*', found {c:?}"),
+                )),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EXAMPLE, Group, Op, part_one, part_two, sum_calculated_groups};
+    use crate::answers::known_answer;
+
+    #[test]
+    fn parse_test_input_part_one() -> anyhow::Result<()> {
+        let expected = vec![
+            Group {
+                numbers: vec![123, 45, 6],
+                operator: Op::Multiply,
+            },
+            Group {
+                numbers: vec![328, 64, 98],
+                operator: Op::Add,
+            },
+            Group {
+                numbers: vec![51, 387, 215],
+                operator: Op::Multiply,
+            },
+            Group {
+                numbers: vec![64, 23, 314],
+                operator: Op::Add,
+            },
+        ];
+        let result = part_one::parse_input(EXAMPLE)?;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    pub fn part_one_test_input() -> anyhow::Result<()> {
+        let groups = part_one::parse_input(EXAMPLE)?;
+        let result = sum_calculated_groups(&groups);
+        let expected = 4277556;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    pub fn part_one_known_answer() -> anyhow::Result<()> {
+        let Some(input) = crate::days::test_input(2025, 6) else {
+            return Ok(());
+        };
+        let groups = part_one::parse_input(&input)?;
+        let result = sum_calculated_groups(&groups);
+        assert_eq!(result.to_string(), known_answer(2025, 6, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_test_input_part_two() -> anyhow::Result<()> {
+        let expected = vec![
+            Group {
+                numbers: vec![1, 24, 356],
+                operator: Op::Multiply,
+            },
+            Group {
+                numbers: vec![369, 248, 8],
+                operator: Op::Add,
+            },
+            Group {
+                numbers: vec![32, 581, 175],
+                operator: Op::Multiply,
+            },
+            Group {
+                numbers: vec![623, 431, 4],
+                operator: Op::Add,
+            },
+        ];
+        let result = part_two::parse_input(EXAMPLE)?;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    pub fn part_two_test_input() -> anyhow::Result<()> {
+        let groups = part_two::parse_input(EXAMPLE)?;
+        let result = sum_calculated_groups(&groups);
+        let expected = 3263827;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    pub fn part_two_known_answer() -> anyhow::Result<()> {
+        let Some(input) = crate::days::test_input(2025, 6) else {
+            return Ok(());
+        };
+        let groups = part_two::parse_input(&input)?;
+        let result = sum_calculated_groups(&groups);
+        assert_eq!(result.to_string(), known_answer(2025, 6, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn bad_input_is_reported() {
+        let err = part_one::parse_input("1 2\n3\n+ *\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 2 numbers, found 1");
+        let err = part_two::parse_input("1 2\n3 4\n+ /\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        let err = part_two::parse_input("12\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        let err = part_two::parse_input("1 4\n2 x\n+ *\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "expected a digit or ' ', found 'x'");
+    }
+
+    #[test]
+    fn blank_columns_are_skipped() -> anyhow::Result<()> {
+        let groups = part_two::parse_input("  12   4  \n   3   5  \n  +    *  \n")?;
+        assert_eq!(sum_calculated_groups(&groups), 1 + 23 + 45);
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+    Grid, Value,
+    parse::ParseError,
+    solver::{Example, Solver},
+};
+
+pub struct Day07;
+
+const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+impl Solver for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 7;
+    const TITLE: &'static str = "Laboratories";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("21"),
+        part_two: Some("40"),
+    }];
+
+    type Parsed = Manifold;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(&self, grid: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        Ok(grid.classic_split())
+    }
+
+    fn part_two(&self, grid: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        Some(Ok(grid.quantum_split()))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Manifold, ParseError> {
+    let cells = Grid::parse(input, |c| match c {
+        '^' | '.' | 'S' => Ok(c),
+        c => Err(format!("expected '^', '.' or 'S', found {c:?}")),
+    })?;
+    let Some((0, start_column)) = cells.find(&'S') else {
+        return Err(ParseError::at_cell(
+            input,
+            (0, 0),
+            "expected 'S' on the first line",
+        ));
+    };
+    // Beams split to either side, so splitters can't be on the edge.
+    let last_column = cells.width() - 1;
+    for (position, _) in cells.cells().filter(|&(_, &c)| c == '^') {
+        if position.1 == 0 || position.1 == last_column {
+            let message = "splitter at the edge of the manifold";
+            return Err(ParseError::at_cell(input, position, message));
+        }
+    }
+    Ok(Manifold {
+        splitters: cells.map(|&c| c == '^'),
+        start_column,
+    })
+}
+
+pub struct Manifold {
+    splitters: Grid<bool>,
+    start_column: usize,
+}
+
+impl Manifold {
+    fn classic_split(&self) -> usize {
+        let mut beam_columns = BTreeSet::from([self.start_column]);
+        let mut times_split = 0;
+        for row in self.splitters.rows().skip(1) {
+            let columns = row.iter().enumerate().filter(|&(_, &splitter)| splitter);
+            for (column, _) in columns {
+                if beam_columns.remove(&column) {
+                    beam_columns.insert(column - 1);
+                    beam_columns.insert(column + 1);
+                    times_split += 1;
+                }
+            }
+        }
+        times_split
+    }
+
+    fn quantum_split(&self) -> usize {
+        let mut timeline_cache = vec![(self.start_column, 1)];
+        let mut working_cache = HashMap::with_capacity(150);
+        for row in self.splitters.rows().skip(1) {
+            for (beam_col, beam_timelines) in timeline_cache {
+                if row[beam_col] {
+                    // n timelines to the left
+                    *working_cache.entry(beam_col - 1).or_default() += beam_timelines;
+                    // n timelines to the right
+                    *working_cache.entry(beam_col + 1).or_default() += beam_timelines;
+                } else {
+                    // No splitter, so n timelines continue in current column
+                    *working_cache.entry(beam_col).or_default() += beam_timelines;
+                }
+            }
+            timeline_cache = working_cache.drain().collect();
+        }
+        timeline_cache
+            .into_iter()
+            .map(|(_, timelines)| timelines)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EXAMPLE;
+    use crate::answers::known_answer;
+
+    #[test]
+    fn parse_test_input() {
+        let grid = super::parse_input(EXAMPLE).unwrap();
+        assert_eq!(grid.start_column, 7);
+        assert!(grid.splitters[(4, 6)]);
+        assert_eq!(grid.splitters.cells().filter(|(_, s)| **s).count(), 22);
+        assert_eq!(grid.splitters.height(), 16);
+    }
+
+    #[test]
+    pub fn part_one_test_input() {
+        let grid = super::parse_input(EXAMPLE).unwrap();
+        let times_split = grid.classic_split();
+        assert_eq!(times_split, 21);
+    }
+
+    #[test]
+    pub fn part_one_known_answer() {
+        let Some(input) = crate::days::test_input(2025, 7) else {
+            return;
+        };
+        let grid = super::parse_input(&input).unwrap();
+        let times_split = grid.classic_split();
+        assert_eq!(times_split.to_string(), known_answer(2025, 7, 1));
+    }
+
+    #[test]
+    pub fn part_two_test_input() {
+        let grid = super::parse_input(EXAMPLE).unwrap();
+        let times_split = grid.quantum_split();
+        assert_eq!(times_split, 40);
+    }
+
+    #[test]
+    pub fn part_two_known_answer() {
+        let Some(input) = crate::days::test_input(2025, 7) else {
+            return;
+        };
+        let grid = super::parse_input(&input).unwrap();
+        let times_split = grid.quantum_split();
+        assert_eq!(times_split.to_string(), known_answer(2025, 7, 2));
+    }
+
+    #[test]
+    fn bad_input_is_reported() {
+        let err = super::parse_input("...\n...\n").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = super::parse_input(".S.\n.^.\n^..\n").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = super::parse_input(".S.\n.#.\n").err().unwrap();
+        assert_eq!(err.message, "expected '^', '.' or 'S', found '#'");
+    }
+}
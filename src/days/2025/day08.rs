@@ -1,21 +1,73 @@
-use std::{cmp::Reverse, collections::BinaryHeap, str::FromStr};
+use std::{cmp::Reverse, collections::BinaryHeap};
 
 use itertools::Itertools;
-use nom::{
-    Parser,
-    bytes::complete::tag,
-    character::complete::u64,
-    combinator::{eof, opt},
-    multi::count,
-    sequence::terminated,
+
+use crate::{
+    Value,
+    parse::{ParseError, parse_lines},
+    solver::{Example, Solver},
+    util::geometry::{Point3, point3},
 };
 
-use crate::util::Answer;
-pub fn solve(input: &str) -> anyhow::Result<String> {
-    let mut graph = Graph::new(parse_input(input)?);
-    let p1 = solve_part_one(&mut graph, 1000)?;
-    let p2 = solve_part_two(&mut graph);
-    Answer::first(8, p1).second(p2).report()
+pub struct Day08;
+
+const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+impl Solver for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 8;
+    const TITLE: &'static str = "Playground";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("40"),
+        part_two: Some("25272"),
+    }];
+
+    /// The graph, and how many of the closest pairs to connect in part one.
+    type Parsed = (Graph, usize);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok((Graph::new(parse_input(input)?), 1000))
+    }
+
+    fn parse_example(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        // The example only connects the ten closest pairs.
+        Ok((Graph::new(parse_input(input)?), 10))
+    }
+
+    fn part_one(
+        &self,
+        (graph, connections): &mut Self::Parsed,
+    ) -> anyhow::Result<impl Into<Value>> {
+        solve_part_one(graph, *connections)
+    }
+
+    fn part_two(&self, (graph, _): &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        // This picks up from the connections made in part one, but the
+        // closest pairs are popped first either way so it can also run alone.
+        Some(solve_part_two(graph))
+    }
 }
 
 fn solve_part_one(graph: &mut Graph, connections: usize) -> anyhow::Result<u64> {
@@ -26,41 +78,15 @@ fn solve_part_one(graph: &mut Graph, connections: usize) -> anyhow::Result<u64>
     Ok(p as u64)
 }
 
-fn solve_part_two(graph: &mut Graph) -> u64 {
-    let (a, b) = graph.exhaust().unwrap();
-    a.0 * b.0
-}
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Point(u64, u64, u64);
-
-impl Point {
-    fn squared_distance(&self, other: &Self) -> u64 {
-        self.0.abs_diff(other.0).pow(2)
-            + self.1.abs_diff(other.1).pow(2)
-            + self.2.abs_diff(other.2).pow(2)
-    }
-}
-
-impl FromStr for Point {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn part(input: &str) -> nom::IResult<&str, u64> {
-            terminated(u64, opt(tag(","))).parse(input)
-        }
-        fn point(input: &str) -> nom::IResult<&str, Point> {
-            let (empty, (parts, _)) = (count(part, 3), eof).parse(input)?;
-            Ok((empty, Point(parts[0], parts[1], parts[2])))
-        }
-        point(s)
-            .map(|(_empty, point)| point)
-            .map_err(|e| e.to_owned().into())
-    }
+fn solve_part_two(graph: &mut Graph) -> anyhow::Result<i64> {
+    let (a, b) = graph
+        .exhaust()
+        .ok_or_else(|| anyhow::anyhow!("Need at least two junction boxes to connect"))?;
+    Ok(a.x * b.x)
 }
 
-fn parse_input(input: &str) -> anyhow::Result<Vec<Point>> {
-    input.lines().map(Point::from_str).collect()
+fn parse_input(input: &str) -> Result<Vec<Point3>, ParseError> {
+    parse_lines(input, point3)
 }
 
 #[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -71,8 +97,8 @@ struct Edge {
 }
 
 // "Graph" but it's really a disjoint set/union-find data structure.
-struct Graph {
-    points: Vec<Point>,
+pub struct Graph {
+    points: Vec<Point3>,
     parents: Vec<usize>,
     sizes: Vec<usize>,
     ordered_edges: BinaryHeap<Reverse<Edge>>,
@@ -80,7 +106,7 @@ struct Graph {
 }
 
 impl Graph {
-    fn new(points: Vec<Point>) -> Self {
+    fn new(points: Vec<Point3>) -> Self {
         let n_components = points.len();
         let ordered_edges = min_heap_from_points(&points);
         Self {
@@ -134,7 +160,7 @@ impl Graph {
         Ok(())
     }
 
-    fn exhaust(&mut self) -> Option<(Point, Point)> {
+    fn exhaust(&mut self) -> Option<(Point3, Point3)> {
         while let Some(Reverse(edge)) = self.ordered_edges.pop() {
             if self.union(edge.a_id, edge.b_id) && self.n_components == 1 {
                 return Some((self.points[edge.a_id], self.points[edge.b_id]));
@@ -150,7 +176,7 @@ impl Graph {
     }
 }
 
-fn min_heap_from_points(points: &[Point]) -> BinaryHeap<Reverse<Edge>> {
+fn min_heap_from_points(points: &[Point3]) -> BinaryHeap<Reverse<Edge>> {
     let edges = points
         .iter()
         .enumerate()
@@ -169,42 +195,21 @@ fn min_heap_from_points(points: &[Point]) -> BinaryHeap<Reverse<Edge>> {
 
 #[cfg(test)]
 mod test {
-    use super::{Graph, Point, parse_input, solve_part_one, solve_part_two};
-
-    static TEST_INPUT: &str = "\
-162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689
-";
+    use super::{EXAMPLE, Graph, parse_input, solve_part_one, solve_part_two};
+    use crate::answers::known_answer;
+    use crate::util::geometry::Point3;
 
     #[test]
     fn parse_test_input() {
-        let points = parse_input(TEST_INPUT).unwrap();
-        assert_eq!(points[0], Point(162, 817, 812));
-        assert_eq!(points[19], Point(425, 690, 689));
+        let points = parse_input(EXAMPLE).unwrap();
+        assert_eq!(points[0], Point3::new(162, 817, 812));
+        assert_eq!(points[19], Point3::new(425, 690, 689));
         assert_eq!(points.len(), 20);
     }
 
     #[test]
     fn test_input_heap() {
-        let points = parse_input(TEST_INPUT).unwrap();
+        let points = parse_input(EXAMPLE).unwrap();
         let graph = Graph::new(points);
         let mut heap = graph.ordered_edges;
         let expected = [(0, 19), (0, 7), (2, 13)];
@@ -216,7 +221,7 @@ mod test {
 
     #[test]
     pub fn small_test_input_union() {
-        let points = parse_input(TEST_INPUT).unwrap();
+        let points = parse_input(EXAMPLE).unwrap();
         let mut graph = Graph::new(points);
         assert_eq!(graph.connect_closest(10), Ok(()));
         assert_eq!(graph.largest_components(3), vec![5, 4, 2]);
@@ -224,35 +229,58 @@ mod test {
 
     #[test]
     pub fn part_one_test_input() {
-        let mut graph = Graph::new(parse_input(TEST_INPUT).unwrap());
+        let mut graph = Graph::new(parse_input(EXAMPLE).unwrap());
         assert_eq!(solve_part_one(&mut graph, 10).unwrap(), 40);
         assert_eq!(graph.n_components, 11);
     }
 
     #[test]
     pub fn part_one_known_answer() {
-        let points = parse_input(crate::days::get_input(8).unwrap()).unwrap();
+        let Some(input) = crate::days::test_input(2025, 8) else {
+            return;
+        };
+        let points = parse_input(&input).unwrap();
         let mut graph = Graph::new(points);
-        assert_eq!(solve_part_one(&mut graph, 1000).unwrap(), 54600);
+        assert_eq!(
+            solve_part_one(&mut graph, 1000).unwrap().to_string(),
+            known_answer(2025, 8, 1)
+        );
     }
 
     #[test]
     pub fn exhaust_test_input() {
-        let mut graph = Graph::new(parse_input(TEST_INPUT).unwrap());
+        let mut graph = Graph::new(parse_input(EXAMPLE).unwrap());
         let o = graph.exhaust();
-        assert_eq!(o, Some((Point(216, 146, 977), Point(117, 168, 530))));
+        assert_eq!(
+            o,
+            Some((Point3::new(216, 146, 977), Point3::new(117, 168, 530)))
+        );
     }
 
     #[test]
     pub fn part_two_test_input() {
-        let mut graph = Graph::new(parse_input(TEST_INPUT).unwrap());
-        assert_eq!(solve_part_two(&mut graph), 25272);
+        let mut graph = Graph::new(parse_input(EXAMPLE).unwrap());
+        assert_eq!(solve_part_two(&mut graph).unwrap(), 25272);
     }
 
     #[test]
     pub fn part_two_known_answer() {
-        let points = parse_input(crate::days::get_input(8).unwrap()).unwrap();
+        let Some(input) = crate::days::test_input(2025, 8) else {
+            return;
+        };
+        let points = parse_input(&input).unwrap();
         let mut graph = Graph::new(points);
-        assert_eq!(solve_part_two(&mut graph), 107256172);
+        assert_eq!(
+            solve_part_two(&mut graph).unwrap().to_string(),
+            known_answer(2025, 8, 2)
+        );
+    }
+
+    #[test]
+    fn bad_input_is_reported() {
+        let err = parse_input("1,2,3\n4,5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        let mut graph = Graph::new(parse_input("1,2,3\n").unwrap());
+        assert!(solve_part_two(&mut graph).is_err());
     }
 }
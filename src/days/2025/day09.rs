@@ -0,0 +1,104 @@
+use itertools::Itertools;
+
+use crate::{
+    Value,
+    parse::{ParseError, parse_lines},
+    solver::{Example, Solver},
+    util::geometry::{Bounds, Point2, point2},
+};
+
+pub struct Day09;
+
+const EXAMPLE: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+impl Solver for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: usize = 9;
+    const TITLE: &'static str = "Movie Theater";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: Some("50"),
+        part_two: None,
+    }];
+
+    type Parsed = Vec<Point2>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one(&self, points: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        solve_part_one(points)
+    }
+}
+
+fn solve_part_one(points: &[Point2]) -> anyhow::Result<u64> {
+    points
+        .iter()
+        .tuple_combinations()
+        .map(|(&a, &b)| Bounds::spanning(a, b).area())
+        .max()
+        .ok_or_else(|| anyhow::anyhow!("Need at least two red tiles to make a rectangle"))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point2>, ParseError> {
+    parse_lines(input, point2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::EXAMPLE;
+    use crate::{answers::known_answer, util::geometry::Point2};
+
+    #[test]
+    fn parse_test_input() {
+        let expected = [
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ]
+        .map(|(x, y)| Point2::new(x, y));
+        let points = super::parse_input(EXAMPLE).unwrap();
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    pub fn part_one_test_input() {
+        let points = super::parse_input(EXAMPLE).unwrap();
+        let res = super::solve_part_one(&points).unwrap();
+        assert_eq!(res, 50);
+    }
+
+    #[test]
+    pub fn part_one_known_answer() {
+        let Some(input) = crate::days::test_input(2025, 9) else {
+            return;
+        };
+        let points = super::parse_input(&input).unwrap();
+        let res = super::solve_part_one(&points).unwrap();
+        assert_eq!(res.to_string(), known_answer(2025, 9, 1));
+    }
+
+    #[test]
+    fn bad_input_is_reported() {
+        let err = super::parse_input("7,1\n11\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = super::parse_input("7,1\n11,x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert!(super::solve_part_one(&[Point2::new(7, 1)]).is_err());
+    }
+}
@@ -1,41 +1,102 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-
-static INPUT: &[&str] = &[
-    include_str!("../../input/2025-01.txt"),
-    include_str!("../../input/2025-02.txt"),
-    include_str!("../../input/2025-03.txt"),
-    include_str!("../../input/2025-04.txt"),
-    include_str!("../../input/2025-05.txt"),
-    include_str!("../../input/2025-06.txt"),
-    include_str!("../../input/2025-07.txt"),
-    include_str!("../../input/2025-08.txt"),
-    include_str!("../../input/2025-09.txt"),
-];
-
-static SOLVERS: &[fn(&str) -> anyhow::Result<String>] = &[
-    day01::solve,
-    day02::solve,
-    day03::solve,
-    day04::solve,
-    day05::solve,
-    day06::solve,
-    day07::solve,
-    day08::solve,
-    day09::solve,
-];
-
-pub fn get_input(day: usize) -> Option<&'static str> {
-    INPUT.get(day - 1).copied()
-}
-
-pub fn get_solver(day: usize) -> Option<&'static fn(&str) -> anyhow::Result<String>> {
-    SOLVERS.get(day - 1)
+//! The solver for each day, one `YYYY/dayNN.rs` module per day of each event.
+//!
+//! The modules and the `SOLVERS` and `INPUT` tables are generated by
+//! `build.rs` from the files in this directory, so a new day only needs its
+//! module (see `aoc_2025 new <day>`).
+
+use crate::{input::InputSource, solver::DynSolver};
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Read the default input for `year`'s `day`, see [`InputSource::Default`].
+pub fn get_input(year: u16, day: usize) -> anyhow::Result<String> {
+    InputSource::Default.read(year, day)
+}
+
+/// The real input for `year`'s `day` in known-answer tests, or `None` if it
+/// isn't present so the test can be skipped on a checkout without private
+/// inputs.
+#[cfg(test)]
+pub(crate) fn test_input(year: u16, day: usize) -> Option<String> {
+    #[cfg(feature = "embedded-input")]
+    let present = embedded_input(year, day).is_some();
+    #[cfg(not(feature = "embedded-input"))]
+    let present = crate::input::default_path(year, day).exists();
+    if !present {
+        return None;
+    }
+    let input = get_input(year, day).expect("Real input exists but failed to read");
+    Some(crate::input::Normalize::default().apply(input))
+}
+
+#[cfg(feature = "embedded-input")]
+pub(crate) fn embedded_input(year: u16, day: usize) -> Option<&'static str> {
+    INPUT
+        .iter()
+        .find(|(input_year, input_day, _)| (*input_year, *input_day) == (year, day))
+        .map(|(_, _, input)| *input)
+}
+
+pub fn get_solver(year: u16, day: usize) -> Option<&'static dyn DynSolver> {
+    SOLVERS
+        .iter()
+        .find(|solver| (solver.year(), solver.day()) == (year, day))
+        .copied()
+}
+
+/// The days of `year` that have a registered solver, in order.
+pub fn implemented(year: u16) -> impl Iterator<Item = usize> {
+    SOLVERS
+        .iter()
+        .filter(move |solver| solver.year() == year)
+        .map(|solver| solver.day())
+}
+
+/// The years with at least one registered solver, in order.
+pub fn years() -> impl Iterator<Item = u16> {
+    let mut years: Vec<_> = SOLVERS.iter().map(|solver| solver.year()).collect();
+    years.dedup();
+    years.into_iter()
+}
+
+/// The most recent year with a registered solver, the default for the CLI.
+pub fn latest_year() -> Option<u16> {
+    years().last()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Parts;
+
+    #[test]
+    fn examples_give_their_expected_answers() -> anyhow::Result<()> {
+        for year in super::years() {
+            for day in super::implemented(year) {
+                let solver = super::get_solver(year, day).unwrap();
+                assert!(
+                    !solver.examples().is_empty(),
+                    "{year} day {day} has no examples"
+                );
+                for example in solver.examples() {
+                    let answer = solver.solve_example(example, Parts::Both)?;
+                    for verdict in example.check(&answer) {
+                        assert!(!verdict.is_fail(), "{year} day {day} example: {verdict}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn solvers_are_keyed_by_year_and_day() {
+        // `build.rs` registers them in directory order, so a solver whose
+        // `YEAR` or `DAY` doesn't match its file is likely to be out of order.
+        let keys: Vec<_> = super::SOLVERS
+            .iter()
+            .map(|solver| (solver.year(), solver.day()))
+            .collect();
+        assert!(keys.is_sorted_by(|a, b| a < b), "{keys:?}");
+        assert_eq!(super::latest_year(), Some(2025));
+    }
 }
@@ -0,0 +1,257 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::solver::DynSolver;
+
+/// Directory searched for `YYYY-NN.txt` puzzle inputs when no path is given.
+pub const INPUT_DIR: &str = "input";
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's file in [`INPUT_DIR`], or the copy compiled into the binary
+    /// when built with the `embedded-input` feature.
+    #[default]
+    Default,
+    /// An explicit file, eg a colleague's input.
+    Path(PathBuf),
+    /// Standard input, given as `-` on the command line.
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse a command-line input argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::Path(PathBuf::from(arg))
+        }
+    }
+
+    /// Read the puzzle input for `year`'s `day` from this source.
+    pub fn read(&self, year: u16, day: usize) -> anyhow::Result<String> {
+        match self {
+            Self::Default => read_default(year, day),
+            Self::Path(path) => read_file(day, path),
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .with_context(|| format!("Failed to read input for day {day} from stdin"))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// The path of the default input file for `year`'s `day`.
+pub fn default_path(year: u16, day: usize) -> PathBuf {
+    path_in(Path::new(INPUT_DIR), year, day)
+}
+
+/// The path of the input file for `year`'s `day` in `dir`, named as in
+/// [`INPUT_DIR`].
+pub fn path_in(dir: &Path, year: u16, day: usize) -> PathBuf {
+    dir.join(format!("{year}-{day:02}.txt"))
+}
+
+/// Other people's inputs for `year`'s `day` in `dir`, as `(name, path)` in
+/// order of name. Each is a file named after its owner in a directory named
+/// like the day's own input, eg `2025-08/alice.txt`.
+pub fn named_inputs(dir: &Path, year: u16, day: usize) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    let day_dir = dir.join(format!("{year}-{day:02}"));
+    let entries = std::fs::read_dir(&day_dir)
+        .with_context(|| format!("No named inputs for day {day} at {}", day_dir.display()))?;
+    let mut inputs = Vec::new();
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("Failed to list {}", day_dir.display()))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "txt")
+            && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+        {
+            inputs.push((name.to_owned(), path));
+        }
+    }
+    if inputs.is_empty() {
+        anyhow::bail!("No named inputs for day {day} in {}", day_dir.display());
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Clean-up applied to puzzle input before a solver sees it, so inputs saved
+/// on Windows or copied from a browser parse the same as downloaded ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    /// Convert `\r\n` line endings to `\n`.
+    pub line_endings: bool,
+    /// Drop a leading byte order mark.
+    pub bom: bool,
+    /// Drop blank lines at the end, leaving a single final newline.
+    pub trailing_blank_lines: bool,
+    /// Drop spaces and tabs at the end of each line, unless the solver says
+    /// they're significant.
+    pub trailing_spaces: bool,
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self {
+            line_endings: true,
+            bom: true,
+            trailing_blank_lines: true,
+            trailing_spaces: false,
+        }
+    }
+}
+
+impl Normalize {
+    /// Leave the input exactly as read.
+    pub const RAW: Self = Self {
+        line_endings: false,
+        bom: false,
+        trailing_blank_lines: false,
+        trailing_spaces: false,
+    };
+
+    /// These options as they apply to `solver`'s input.
+    pub fn for_solver(self, solver: &dyn DynSolver) -> Self {
+        Self {
+            trailing_spaces: self.trailing_spaces && !solver.significant_spaces(),
+            ..self
+        }
+    }
+
+    pub fn apply(&self, mut input: String) -> String {
+        if self.bom && input.starts_with('\u{feff}') {
+            input.drain(..'\u{feff}'.len_utf8());
+        }
+        if self.line_endings && input.contains('\r') {
+            input = input.replace("\r\n", "\n");
+        }
+        if self.trailing_spaces {
+            let mut trimmed = String::with_capacity(input.len());
+            for line in input.split_inclusive('\n') {
+                let (text, newline) = match line.strip_suffix('\n') {
+                    Some(text) => (text, "\n"),
+                    None => (line, ""),
+                };
+                trimmed.push_str(text.trim_end_matches([' ', '\t']));
+                trimmed.push_str(newline);
+            }
+            input = trimmed;
+        }
+        if self.trailing_blank_lines {
+            let end = input.trim_end_matches('\n').len();
+            input.truncate(end);
+            if end > 0 {
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+#[cfg(feature = "embedded-input")]
+fn read_default(year: u16, day: usize) -> anyhow::Result<String> {
+    match crate::days::embedded_input(year, day) {
+        Some(input) => Ok(input.to_owned()),
+        None => read_file(day, &default_path(year, day)),
+    }
+}
+
+#[cfg(not(feature = "embedded-input"))]
+fn read_default(year: u16, day: usize) -> anyhow::Result<String> {
+    read_file(day, &default_path(year, day))
+}
+
+fn read_file(day: usize, path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("No input for day {day} at {}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{InputSource, Normalize, default_path, named_inputs};
+    use crate::util::temp_dir;
+
+    #[test]
+    fn dash_means_stdin() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("alice.txt"),
+            InputSource::Path(PathBuf::from("alice.txt"))
+        );
+    }
+
+    #[test]
+    fn default_path_is_zero_padded() {
+        assert_eq!(default_path(2025, 7), PathBuf::from("input/2025-07.txt"));
+        assert_eq!(default_path(2024, 12), PathBuf::from("input/2024-12.txt"));
+    }
+
+    #[test]
+    fn missing_file_names_the_day() {
+        let source = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+        let err = source.read(2025, 3).unwrap_err();
+        assert!(err.to_string().starts_with("No input for day 3"));
+    }
+
+    #[test]
+    fn named_inputs_are_listed_by_name() -> anyhow::Result<()> {
+        let dir = temp_dir("named-inputs");
+        assert!(named_inputs(&dir, 2025, 8).is_err());
+
+        let day_dir = dir.join("2025-08");
+        std::fs::create_dir(&day_dir)?;
+        for file in ["carol.txt", "alice.txt", "notes.md"] {
+            std::fs::write(day_dir.join(file), "1,2,3\n")?;
+        }
+        let names: Vec<_> = named_inputs(&dir, 2025, 8)?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["alice", "carol"]);
+        Ok(())
+    }
+
+    #[test]
+    fn windows_input_is_normalized() {
+        let input = "\u{feff}1,2\r\n3,4  \r\n\r\n\r\n".to_owned();
+        assert_eq!(Normalize::default().apply(input.clone()), "1,2\n3,4  \n");
+        let trim = Normalize {
+            trailing_spaces: true,
+            ..Normalize::default()
+        };
+        assert_eq!(trim.apply(input.clone()), "1,2\n3,4\n");
+        assert_eq!(Normalize::RAW.apply(input.clone()), input);
+    }
+
+    #[test]
+    fn final_newline_is_added() {
+        let normalize = Normalize::default();
+        assert_eq!(normalize.apply("1\n2".to_owned()), "1\n2\n");
+        assert_eq!(normalize.apply("\n\n".to_owned()), "");
+        assert_eq!(normalize.apply(String::new()), "");
+    }
+
+    #[test]
+    fn significant_spaces_are_kept() {
+        let trim = Normalize {
+            trailing_spaces: true,
+            ..Normalize::default()
+        };
+        let day06 = crate::days::get_solver(2025, 6).unwrap();
+        assert!(!trim.for_solver(day06).trailing_spaces);
+        let day01 = crate::days::get_solver(2025, 1).unwrap();
+        assert!(trim.for_solver(day01).trailing_spaces);
+    }
+}
@@ -1,17 +1,118 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
 pub mod days;
+pub mod input;
+pub mod parse;
+pub mod report;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+pub mod trace;
 mod util;
+pub mod watch;
+
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use input::{InputSource, Normalize};
+use report::{DayResult, InputResult};
+use solver::Example;
+pub use util::{Answer, Grid, IntervalSet, Parts, Timings, Value, geometry};
 
 #[tracing::instrument]
-pub fn run(day: usize) -> anyhow::Result<String> {
-    assert_ne!(day, 0, "Day must be >= 1.");
+pub fn run(
+    year: u16,
+    day: usize,
+    source: &InputSource,
+    parts: Parts,
+    normalize: Normalize,
+) -> anyhow::Result<Answer> {
+    if day == 0 {
+        anyhow::bail!("Day must be >= 1.");
+    }
 
-    let Some(input) = days::get_input(day) else {
-        anyhow::bail!("No input for day {day}.");
+    let Some(solver) = days::get_solver(year, day) else {
+        anyhow::bail!("Day {day} of {year} is not implemented yet.");
     };
-    let Some(day_fn) = days::get_solver(day) else {
-        anyhow::bail!("Day {day} is not implemented yet.");
+    let input = normalize.for_solver(solver).apply(source.read(year, day)?);
+    solver.solve(&input, parts)
+}
+
+/// Solve `year`'s `days` on up to `jobs` threads, with the results in the
+/// order of `days` however the work was scheduled.
+pub fn run_days(
+    year: u16,
+    days: &[usize],
+    source: &InputSource,
+    parts: Parts,
+    normalize: Normalize,
+    jobs: usize,
+) -> Vec<DayResult> {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<_> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, days.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let idx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&day) = days.get(idx) else {
+                            return done;
+                        };
+                        done.push((idx, run(year, day, source, parts, normalize)));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+    results.sort_by_key(|(idx, _)| *idx);
+    results
+        .into_iter()
+        .map(|(idx, result)| (days[idx], result))
+        .collect()
+}
+
+/// Solve `year`'s `day` for each of the named `inputs`, eg from
+/// [`input::named_inputs`], in the same order.
+pub fn run_inputs(
+    year: u16,
+    day: usize,
+    inputs: &[(String, PathBuf)],
+    parts: Parts,
+    normalize: Normalize,
+) -> Vec<InputResult> {
+    inputs
+        .iter()
+        .map(|(name, path)| {
+            let source = InputSource::Path(path.clone());
+            (name.clone(), run(year, day, &source, parts, normalize))
+        })
+        .collect()
+}
+
+/// Solve each of `year`'s `day`'s registered examples, paired with the
+/// example.
+#[tracing::instrument]
+pub fn run_examples(year: u16, day: usize, parts: Parts) -> anyhow::Result<Vec<(Example, Answer)>> {
+    let Some(solver) = days::get_solver(year, day) else {
+        anyhow::bail!("Day {day} of {year} is not implemented yet.");
     };
-    day_fn(input)
+    if solver.examples().is_empty() {
+        anyhow::bail!("Day {day} has no examples.");
+    }
+    solver
+        .examples()
+        .iter()
+        .map(|example| Ok((*example, solver.solve_example(example, parts)?)))
+        .collect()
 }
 
 /// Handy extension methods for numeric types.
@@ -32,3 +133,33 @@ macro_rules! impl_NumUtil {
 }
 
 impl_NumUtil!(u8 u16 u32 u64);
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::{
+        Parts,
+        input::{InputSource, Normalize},
+    };
+
+    #[test]
+    fn parallel_results_keep_their_order() {
+        let source = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+        let days = [8, 2, 5, 1, 9, 3];
+        let results = super::run_days(2025, &days, &source, Parts::Both, Normalize::default(), 4);
+        let order: Vec<_> = results.iter().map(|(day, _)| *day).collect();
+        assert_eq!(order, days);
+        for (day, result) in &results {
+            let err = result.as_ref().unwrap_err().to_string();
+            assert!(err.starts_with(&format!("No input for day {day} ")));
+        }
+    }
+
+    #[test]
+    fn day_zero_is_an_error() {
+        let source = InputSource::Path(PathBuf::from("does/not/exist.txt"));
+        let err = super::run(2025, 0, &source, Parts::Both, Normalize::default()).unwrap_err();
+        assert_eq!(err.to_string(), "Day must be >= 1.");
+    }
+}
@@ -1,13 +1,229 @@
+use std::time::Instant;
+
+use aoc_2025::{
+    answers::KnownAnswers,
+    bench,
+    cli::{Args, Command},
+    client::{self, Client, Fetched},
+    input::{self, INPUT_DIR, InputSource},
+    report::{self, DayResult, ExampleResult},
+    scaffold,
+    submit::{self, Outcome},
+    trace,
+    watch::{self, Watcher},
+};
+
 fn main() -> anyhow::Result<()> {
-    // Enable `tracing` logging.
-    tracing_subscriber::fmt::init();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = aoc_2025::cli::parse_args(&args)?;
+    // Enable `tracing` logging, held until exit so the trace file is complete.
+    let _trace = trace::init(args.log.as_deref(), args.trace.as_deref())?;
+
+    let failed: Vec<_> = match args.command {
+        Command::Run | Command::Verify if args.example => {
+            let results: Vec<ExampleResult> = args
+                .days
+                .iter()
+                .map(|&day| (day, aoc_2025::run_examples(args.year, day, args.parts)))
+                .collect();
+            print!("{}", report::render_examples(&results));
+            results
+                .iter()
+                .filter(|(_, result)| report::examples_failed(result))
+                .map(|(day, _)| day.to_string())
+                .collect()
+        }
+        Command::Run => {
+            let known = KnownAnswers::load(&args.answers)?;
+            let start = Instant::now();
+            let results = run_days(&args);
+            print!(
+                "{}",
+                report::render(args.format, args.year, &results, &known, start.elapsed())
+            );
+            results
+                .iter()
+                .filter(|(_, result)| result.is_err())
+                .map(|(day, _)| day.to_string())
+                .collect()
+        }
+        Command::Verify => {
+            let known = KnownAnswers::load(&args.answers)?;
+            let results = run_days(&args);
+            print!(
+                "{}",
+                report::render_verification(args.year, &results, &known)
+            );
+            results
+                .iter()
+                .filter(|(_, result)| match result {
+                    Ok(answer) => known.check(args.year, answer).iter().any(|v| v.is_fail()),
+                    Err(_) => true,
+                })
+                .map(|(day, _)| day.to_string())
+                .collect()
+        }
+        Command::Bench => return run_bench(&args),
+        Command::Fetch => return run_fetch(&args),
+        Command::Submit => return run_submit(&args),
+        Command::New => {
+            let day = args.days[0];
+            let title = args.title.clone().unwrap_or_else(|| format!("Day {day}"));
+            let path = scaffold::create(&scaffold::days_dir(), args.year, day, &title)?;
+            println!("Created {}", path.display());
+            return Ok(());
+        }
+        Command::Watch => return run_watch(&args),
+        Command::Compare => {
+            let &[day] = args.days.as_slice() else {
+                anyhow::bail!("Only one day can be compared at a time.");
+            };
+            let known = KnownAnswers::load(&args.answers)?;
+            let inputs = input::named_inputs(INPUT_DIR.as_ref(), args.year, day)?;
+            let results = aoc_2025::run_inputs(args.year, day, &inputs, args.parts, args.normalize);
+            print!(
+                "{}",
+                report::render_comparison(args.year, day, &results, &known)
+            );
+            let failed: Vec<_> = results
+                .iter()
+                .filter(|(name, result)| match result {
+                    Ok(answer) => known
+                        .check_for(Some(name), args.year, answer)
+                        .iter()
+                        .any(|v| v.is_fail()),
+                    Err(_) => true,
+                })
+                .map(|(name, _)| name.as_str())
+                .collect();
+            if !failed.is_empty() {
+                anyhow::bail!("{} input(s) failed: {}", failed.len(), failed.join(", "));
+            }
+            return Ok(());
+        }
+    };
+    if !failed.is_empty() {
+        anyhow::bail!("{} day(s) failed: {}", failed.len(), failed.join(", "));
+    }
+    Ok(())
+}
+
+fn run_days(args: &Args) -> Vec<DayResult> {
+    aoc_2025::run_days(
+        args.year,
+        &args.days,
+        &args.input,
+        args.parts,
+        args.normalize,
+        args.jobs,
+    )
+}
 
-    let arg = std::env::args().nth(1);
-    let Some(Ok(day)) = arg.map(|s| s.parse::<usize>()) else {
-        anyhow::bail!("You must give the day to run.");
+fn run_bench(args: &Args) -> anyhow::Result<()> {
+    let options = &args.bench;
+    let baseline = match &options.compare {
+        Some(path) => Some(bench::from_saved(&std::fs::read_to_string(path)?)?),
+        None => None,
     };
-    let solution = aoc_2025::run(day)?;
-    println!("{solution}");
 
+    let mut benches = Vec::new();
+    for &day in &args.days {
+        match bench::bench_day(
+            args.year,
+            day,
+            &args.input,
+            args.parts,
+            args.normalize,
+            options,
+        ) {
+            Ok(result) => benches.push(result),
+            Err(e) => eprintln!("Day {day} failed: {e:#}"),
+        }
+    }
+    print!("{}", bench::render(&benches, baseline.as_deref()));
+
+    if let Some(path) = &options.save {
+        std::fs::write(path, bench::to_saved(&benches))?;
+    }
+    if let Some(baseline) = &baseline {
+        let regressed = bench::regressions(&benches, baseline);
+        if !regressed.is_empty() {
+            let phases: Vec<_> = regressed
+                .iter()
+                .map(|(year, day, phase)| format!("{year} day {day} {phase}"))
+                .collect();
+            anyhow::bail!("Regressions in {}", phases.join(", "));
+        }
+    }
+    if benches.len() != args.days.len() {
+        anyhow::bail!("{} day(s) failed.", args.days.len() - benches.len());
+    }
+    Ok(())
+}
+
+fn run_fetch(args: &Args) -> anyhow::Result<()> {
+    let client = Client::from_env(&args.base_url);
+    let mut failed = 0;
+    for &day in &args.days {
+        match client::fetch_input(&client, args.year, day, INPUT_DIR.as_ref()) {
+            Ok(Fetched::Downloaded(path)) => println!("Day {day}: saved to {}", path.display()),
+            Ok(Fetched::Cached(path)) => println!("Day {day}: already at {}", path.display()),
+            Err(e) => {
+                eprintln!("Day {day} failed: {e:#}");
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} day(s) failed.");
+    }
     Ok(())
 }
+
+fn run_submit(args: &Args) -> anyhow::Result<()> {
+    let &[day] = args.days.as_slice() else {
+        anyhow::bail!("Only one day can be submitted at a time.");
+    };
+    let answer = aoc_2025::run(args.year, day, &args.input, args.parts, args.normalize)?;
+    let (part, computed) = if args.parts.includes_one() {
+        (1, answer.part_one())
+    } else {
+        (2, answer.part_two())
+    };
+    let Some(computed) = computed else {
+        anyhow::bail!("Day {day} part {part} isn't solved yet.");
+    };
+    let client = Client::from_env(&args.base_url);
+    let computed = computed.to_string();
+    let outcome = submit::submit(&client, &args.history, (args.year, day, part), &computed)?;
+    println!("Day {day} part {part}: submitted {computed}, {outcome}");
+    match outcome {
+        Outcome::Correct | Outcome::AlreadySolved => Ok(()),
+        _ => anyhow::bail!("Day {day} part {part} wasn't accepted."),
+    }
+}
+
+fn run_watch(args: &Args) -> anyhow::Result<()> {
+    let (&[day], InputSource::Path(path)) = (args.days.as_slice(), &args.input) else {
+        anyhow::bail!("watch needs a single day and an input file.");
+    };
+    let mut watcher = Watcher::new(path);
+    let mut previous = None;
+    println!("Watching {} for changes, Ctrl-C to stop.\n", path.display());
+    loop {
+        if watcher.poll() {
+            match aoc_2025::run(args.year, day, &args.input, args.parts, args.normalize) {
+                Ok(answer) => {
+                    print!("{answer}");
+                    if let Some(previous) = &previous {
+                        print!("{}", watch::diff(previous, &answer));
+                    }
+                    println!();
+                    previous = Some(answer);
+                }
+                Err(e) => println!("Day {day} failed: {e:#}\n"),
+            }
+        }
+        std::thread::sleep(watch::POLL_INTERVAL);
+    }
+}
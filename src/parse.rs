@@ -0,0 +1,173 @@
+use std::fmt::Display;
+
+/// Puzzle input that failed to parse, located by line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub message: String,
+    /// The text of the offending line.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// An error at the start of `rest`, which must be a slice of `input`,
+    /// eg what a parser had left or one of `input.lines()`.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+        Self::at_offset(input, offset, message)
+    }
+
+    /// An error at byte `offset` into `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+
+    /// An error at the character in `(row, column)` of `input` read as a grid,
+    /// counting both from 0 like [`Grid`](crate::Grid) does.
+    pub fn at_cell(input: &str, (row, column): (usize, usize), message: impl Into<String>) -> Self {
+        let Some(line) = input.lines().nth(row) else {
+            return Self::at_offset(input, input.len(), message);
+        };
+        let idx = line
+            .char_indices()
+            .nth(column)
+            .map_or(line.len(), |(idx, _)| idx);
+        Self::at(input, &line[idx..], message)
+    }
+
+    /// Convert an error from a nom parser run over `input`.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let found = match e.input.chars().next() {
+                    Some('\n') => "end of line".to_owned(),
+                    Some(c) => format!("{c:?}"),
+                    None => "end of input".to_owned(),
+                };
+                Self::at(
+                    input,
+                    e.input,
+                    format!("unexpected {found} ({})", e.code.description()),
+                )
+            }
+            nom::Err::Incomplete(_) => {
+                Self::at_offset(input, input.len(), "unexpected end of input")
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  | {}", self.source_line)?;
+        write!(f, "  | {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Run a nom `parser` over the whole of `input`, which it must consume.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl nom::Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+) -> Result<O, ParseError> {
+    match parser.parse(input) {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(ParseError::at(input, rest, "unexpected trailing input")),
+        Err(e) => Err(ParseError::from_nom(input, e)),
+    }
+}
+
+/// Run a nom `parser` over each line of `input`, which it must consume.
+/// Lines are parsed one at a time, so errors point inside the bad line.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut parser: impl nom::Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+) -> Result<Vec<O>, ParseError> {
+    input
+        .lines()
+        .map(|line| match parser.parse(line) {
+            Ok(("", output)) => Ok(output),
+            Ok((rest, _)) => Err(ParseError::at(input, rest, "unexpected trailing input")),
+            Err(e) => Err(ParseError::from_nom(input, e)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{
+        character::complete::{newline, u64},
+        multi::separated_list1,
+    };
+
+    use super::{ParseError, parse_all, parse_lines};
+
+    #[test]
+    fn errors_are_located() {
+        let input = "12\n34\n5x6\n";
+        let err = parse_all(input, separated_list1(newline, u64)).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 2));
+        assert_eq!(err.source_line, "5x6");
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 2: unexpected trailing input\n  | 5x6\n  |  ^"
+        );
+    }
+
+    #[test]
+    fn nom_errors_name_what_was_found() {
+        // The list stops before the blank line, so the rest is left over.
+        let err = parse_all("1\n\n2", separated_list1(newline, u64)).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 2));
+        let err = parse_all("1\nx", (u64, newline, u64)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(err.message.starts_with("unexpected 'x'"), "{}", err.message);
+        let err = parse_all("1\n", (u64, newline, u64)).unwrap_err();
+        assert!(err.message.starts_with("unexpected end of input"));
+    }
+
+    #[test]
+    fn lines_are_parsed_separately() {
+        assert_eq!(parse_lines("1\n22\n", u64), Ok(vec![1, 22]));
+        let err = parse_lines("1\n2x\n", u64).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn errors_at_a_line() {
+        let input = "7,1\n11\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(input, line, "expected x,y");
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.source_line, "11");
+        // A slice from elsewhere is reported at the end of the input.
+        let err = ParseError::at(input, "other", "oops");
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = ParseError::at_cell(input, (1, 1), "oops");
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
@@ -0,0 +1,620 @@
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use crate::{
+    answers::{KnownAnswers, Verdict},
+    solver::Example,
+    util::{Answer, TableRow, TableStyle, Value, render_table},
+};
+
+/// The result of running one day, as collected by the binary.
+pub type DayResult = (usize, anyhow::Result<Answer>);
+
+/// The result of running a day on one of its named inputs, by name.
+pub type InputResult = (String, anyhow::Result<Answer>);
+
+/// The results of running one day's examples.
+pub type ExampleResult = (usize, anyhow::Result<Vec<(Example, Answer)>>);
+
+/// Output format for a run's results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A human-readable answer block for one day, or a table of the days'
+    /// answers for several.
+    #[default]
+    Text,
+    /// A Markdown table of the days' answers.
+    Markdown,
+    /// A single JSON object with one entry per day.
+    Json,
+    /// A header row and one row per day.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "markdown" => Ok(Self::Markdown),
+            _ => anyhow::bail!("Unknown format {s:?}, expected text, json, csv or markdown."),
+        }
+    }
+}
+
+/// Render the results of every day of `year` run in the given format, where
+/// `elapsed` is the wall-clock time for the whole run. That's less than the
+/// total of the days' times when they ran in parallel. Tables show whether
+/// each day's answers match the `known` answers.
+pub fn render(
+    format: Format,
+    year: u16,
+    results: &[DayResult],
+    known: &KnownAnswers,
+    elapsed: Duration,
+) -> String {
+    match format {
+        Format::Text => render_text(year, results, known, elapsed),
+        Format::Markdown => table(year, results, known, TableStyle::Markdown),
+        Format::Json => render_json(year, results, elapsed),
+        Format::Csv => render_csv(year, results),
+    }
+}
+
+fn table(year: u16, results: &[DayResult], known: &KnownAnswers, style: TableStyle) -> String {
+    let rows: Vec<_> = results
+        .iter()
+        .map(|(day, result)| TableRow {
+            day: *day,
+            result,
+            verdicts: match result {
+                Ok(answer) => known.check(year, answer),
+                Err(_) => [Verdict::NotRun, Verdict::NotRun],
+            },
+        })
+        .collect();
+    render_table(&rows, style)
+}
+
+fn total_time(results: &[DayResult]) -> Duration {
+    results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok())
+        .map(|answer| answer.timings().total())
+        .sum()
+}
+
+/// Total CPU time, or `None` if no day ran or it wasn't measured for every
+/// day that did.
+fn total_cpu(results: &[DayResult]) -> Option<Duration> {
+    let mut answers = results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok())
+        .peekable();
+    answers.peek()?;
+    answers.map(|answer| answer.timings().cpu).sum()
+}
+
+fn render_text(
+    year: u16,
+    results: &[DayResult],
+    known: &KnownAnswers,
+    elapsed: Duration,
+) -> String {
+    let [(day, result)] = results else {
+        let mut out = table(year, results, known, TableStyle::Text);
+        writeln!(out, "\nElapsed: {elapsed:.1?}").unwrap();
+        if let Some(cpu) = total_cpu(results) {
+            writeln!(out, "CPU:     {cpu:.1?}").unwrap();
+        }
+        return out;
+    };
+    match result {
+        Ok(answer) => format!("{answer}\n"),
+        Err(e) => format!("Day {day} failed: {e:#}\n\n"),
+    }
+}
+
+/// Render results as JSON.
+///
+/// The schema is:
+///
+/// ```text
+/// {"days": [{"year": 2025, "day": 1, "status": "ok" | "error",
+///            "part_one": "..." | null, "part_two": "..." | null,
+///            "timings_ns": {"parse": n | null, "part_one": n | null,
+///                           "part_two": n | null, "total": n,
+///                           "cpu": n | null},
+///            "error": "..." | null}],
+///  "total_ns": n, "elapsed_ns": n}
+/// ```
+///
+/// Answers are strings so that large or non-numeric answers survive intact.
+fn render_json(year: u16, results: &[DayResult], elapsed: Duration) -> String {
+    let days: Vec<String> = results
+        .iter()
+        .map(|(day, result)| {
+            let (part_one, part_two, timings, error) = match result {
+                Ok(answer) => (
+                    json_string(rendered(answer.part_one()).as_deref()),
+                    json_string(rendered(answer.part_two()).as_deref()),
+                    *answer.timings(),
+                    json_string(None),
+                ),
+                Err(e) => (
+                    json_string(None),
+                    json_string(None),
+                    Default::default(),
+                    json_string(Some(&format!("{e:#}"))),
+                ),
+            };
+            format!(
+                concat!(
+                    r#"{{"year":{},"day":{},"status":"{}","part_one":{},"part_two":{},"#,
+                    r#""timings_ns":{{"parse":{},"part_one":{},"part_two":{},"total":{},"cpu":{}}},"#,
+                    r#""error":{}}}"#
+                ),
+                year,
+                day,
+                status(result),
+                part_one,
+                part_two,
+                json_nanos(timings.parse),
+                json_nanos(timings.part_one),
+                json_nanos(timings.part_two),
+                timings.total().as_nanos(),
+                json_nanos(timings.cpu),
+                error,
+            )
+        })
+        .collect();
+    format!(
+        r#"{{"days":[{}],"total_ns":{},"elapsed_ns":{}}}"#,
+        days.join(","),
+        total_time(results).as_nanos(),
+        elapsed.as_nanos()
+    ) + "\n"
+}
+
+/// A part's answer as text, for formats that don't keep its type.
+fn rendered(value: Option<&Value>) -> Option<String> {
+    value.map(Value::to_string)
+}
+
+fn status(result: &anyhow::Result<Answer>) -> &'static str {
+    if result.is_ok() { "ok" } else { "error" }
+}
+
+fn json_string(s: Option<&str>) -> String {
+    let Some(s) = s else {
+        return "null".to_owned();
+    };
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_nanos(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| "null".to_owned(), |d| d.as_nanos().to_string())
+}
+
+/// Render results as CSV, with empty fields for parts that weren't computed.
+fn render_csv(year: u16, results: &[DayResult]) -> String {
+    let mut out = String::from(
+        "year,day,status,part_one,part_two,parse_ns,part_one_ns,part_two_ns,total_ns,cpu_ns,error\n",
+    );
+    for (day, result) in results {
+        let nanos = |d: Option<Duration>| d.map(|d| d.as_nanos().to_string()).unwrap_or_default();
+        let row = match result {
+            Ok(answer) => {
+                let timings = answer.timings();
+                [
+                    year.to_string(),
+                    day.to_string(),
+                    status(result).to_owned(),
+                    csv_field(&rendered(answer.part_one()).unwrap_or_default()),
+                    csv_field(&rendered(answer.part_two()).unwrap_or_default()),
+                    nanos(timings.parse),
+                    nanos(timings.part_one),
+                    nanos(timings.part_two),
+                    timings.total().as_nanos().to_string(),
+                    nanos(timings.cpu),
+                    String::new(),
+                ]
+            }
+            Err(e) => [
+                year.to_string(),
+                day.to_string(),
+                status(result).to_owned(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                csv_field(&format!("{e:#}")),
+            ],
+        };
+        writeln!(out, "{}", row.join(",")).unwrap();
+    }
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Render the pass/fail/unknown status of every part of `year`'s results
+/// against the known answers.
+pub fn render_verification(year: u16, results: &[DayResult], known: &KnownAnswers) -> String {
+    let mut out = String::new();
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for (day, result) in results {
+        match result {
+            Ok(answer) => {
+                let [one, two] = known.check(year, answer);
+                for verdict in [&one, &two] {
+                    match verdict {
+                        Verdict::Pass => passed += 1,
+                        Verdict::Fail { .. } => failed += 1,
+                        Verdict::Unknown => unknown += 1,
+                        Verdict::NotRun => {}
+                    }
+                }
+                writeln!(out, "Day {day:<3} part one: {one:<10} part two: {two}")
+            }
+            Err(e) => {
+                failed += 1;
+                writeln!(out, "Day {day:<3} failed: {e:#}")
+            }
+        }
+        .unwrap();
+    }
+    writeln!(out, "\n{passed} passed, {failed} failed, {unknown} unknown").unwrap();
+    out
+}
+
+/// Render the answers from each of `year`'s `day`'s named inputs side by
+/// side, with whether each matches a known answer for that input.
+pub fn render_comparison(
+    year: u16,
+    day: usize,
+    results: &[InputResult],
+    known: &KnownAnswers,
+) -> String {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    let mut rows = Vec::new();
+    for (name, result) in results {
+        let row = match result {
+            Ok(answer) => {
+                let [one, two] = known.check_for(Some(name), year, answer);
+                let cells = [(answer.part_one(), one), (answer.part_two(), two)].map(
+                    |(computed, verdict)| match verdict {
+                        Verdict::Pass => {
+                            passed += 1;
+                            format!("{} (verified)", rendered(computed).unwrap_or_default())
+                        }
+                        Verdict::Fail { expected, actual } => {
+                            failed += 1;
+                            format!("{actual} (WRONG, expected {expected})")
+                        }
+                        Verdict::Unknown => {
+                            unknown += 1;
+                            rendered(computed).unwrap_or_default()
+                        }
+                        Verdict::NotRun => "-".to_owned(),
+                    },
+                );
+                Ok(cells)
+            }
+            Err(e) => {
+                failed += 1;
+                Err(format!("failed: {e:#}"))
+            }
+        };
+        rows.push((name, row));
+    }
+
+    let name_width = results
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(["Input".len()])
+        .max()
+        .unwrap_or_default();
+    let one_width = rows
+        .iter()
+        .filter_map(|(_, row)| row.as_ref().ok())
+        .map(|[one, _]| one.len())
+        .chain(["Part one".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    writeln!(out, "Day {day} of {year}, {} input(s)\n", results.len()).unwrap();
+    writeln!(
+        out,
+        "{:<name_width$}  {:<one_width$}  Part two",
+        "Input", "Part one"
+    )
+    .unwrap();
+    for (name, row) in rows {
+        match row {
+            Ok([one, two]) => writeln!(out, "{name:<name_width$}  {one:<one_width$}  {two}"),
+            Err(e) => writeln!(out, "{name:<name_width$}  {e}"),
+        }
+        .unwrap();
+    }
+    writeln!(out, "\n{passed} passed, {failed} failed, {unknown} unknown").unwrap();
+    out
+}
+
+/// Render each example's answers, followed by how they compare with the
+/// example's expected answers.
+pub fn render_examples(results: &[ExampleResult]) -> String {
+    let mut out = String::new();
+    for (day, result) in results {
+        let examples = match result {
+            Ok(examples) => examples,
+            Err(e) => {
+                writeln!(out, "Day {day} failed: {e:#}\n").unwrap();
+                continue;
+            }
+        };
+        for (idx, (example, answer)) in examples.iter().enumerate() {
+            let [one, two] = example.check(answer);
+            writeln!(
+                out,
+                "{answer}Example {}: part one: {one:<10} part two: {two}\n",
+                idx + 1
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+/// Whether any example failed to run or gave a wrong answer.
+pub fn examples_failed(result: &anyhow::Result<Vec<(Example, Answer)>>) -> bool {
+    match result {
+        Ok(examples) => examples
+            .iter()
+            .any(|(example, answer)| example.check(answer).iter().any(Verdict::is_fail)),
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{
+        DayResult, Format, InputResult, examples_failed, render, render_comparison,
+        render_examples, render_verification,
+    };
+    use crate::{
+        answers::KnownAnswers,
+        solver::Example,
+        util::{Answer, Parts, Timings},
+    };
+
+    const ELAPSED: Duration = Duration::from_nanos(125);
+
+    fn results() -> Vec<DayResult> {
+        let answer = Answer::new(3, Parts::Both)
+            .first(|| 357)
+            .second(|| "a \"quoted\", line")
+            .with_timings(Timings {
+                parse: Some(Duration::from_nanos(100)),
+                part_one: Some(Duration::from_nanos(20)),
+                part_two: Some(Duration::from_nanos(3)),
+                cpu: Some(Duration::from_nanos(110)),
+            });
+        let partial = Answer::new(9, Parts::One)
+            .first(|| 50)
+            .with_timings(Timings {
+                parse: None,
+                part_one: Some(Duration::from_nanos(7)),
+                part_two: None,
+                cpu: Some(Duration::from_nanos(6)),
+            });
+        vec![
+            (3, Ok(answer)),
+            (4, Err(anyhow::anyhow!("No input for day 4"))),
+            (9, Ok(partial)),
+        ]
+    }
+
+    #[test]
+    fn json_schema() {
+        let expected = concat!(
+            r#"{"days":["#,
+            r#"{"year":2025,"day":3,"status":"ok","part_one":"357","part_two":"a \"quoted\", line","#,
+            r#""timings_ns":{"parse":100,"part_one":20,"part_two":3,"total":123,"cpu":110},"#,
+            r#""error":null},"#,
+            r#"{"year":2025,"day":4,"status":"error","part_one":null,"part_two":null,"#,
+            r#""timings_ns":{"parse":null,"part_one":null,"part_two":null,"total":0,"cpu":null},"#,
+            r#""error":"No input for day 4"},"#,
+            r#"{"year":2025,"day":9,"status":"ok","part_one":"50","part_two":null,"#,
+            r#""timings_ns":{"parse":null,"part_one":7,"part_two":null,"total":7,"cpu":6},"#,
+            r#""error":null}"#,
+            r#"],"total_ns":130,"elapsed_ns":125}"#,
+            "\n"
+        );
+        assert_eq!(
+            render(
+                Format::Json,
+                2025,
+                &results(),
+                &KnownAnswers::default(),
+                ELAPSED
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn csv_schema() {
+        let expected = "\
+year,day,status,part_one,part_two,parse_ns,part_one_ns,part_two_ns,total_ns,cpu_ns,error
+2025,3,ok,357,\"a \"\"quoted\"\", line\",100,20,3,123,110,
+2025,4,error,,,,,,,,No input for day 4
+2025,9,ok,50,,,7,,7,6,
+";
+        assert_eq!(
+            render(
+                Format::Csv,
+                2025,
+                &results(),
+                &KnownAnswers::default(),
+                ELAPSED
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn text_tabulates_several_days() -> anyhow::Result<()> {
+        let known =
+            KnownAnswers::parse("2025 3 1 357\n2025 3 2 a \"quoted\", line\n2025 9 1 51\n")?;
+        let text = render(Format::Text, 2025, &results(), &known, ELAPSED);
+        let expected = "\
+Day                   Part one          Part two     Time  Verified
+3                          357  a \"quoted\", line  123.0ns  yes
+4                       FAILED                 -        -  FAIL
+  No input for day 4
+9      50 (WRONG, expected 51)                 -    7.0ns  FAIL
+Total                                             130.0ns
+
+Elapsed: 125.0ns
+CPU:     116.0ns
+";
+        assert_eq!(text, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn cpu_is_left_out_when_every_day_failed() {
+        let results: Vec<DayResult> = vec![
+            (4, Err(anyhow::anyhow!("No input for day 4"))),
+            (5, Err(anyhow::anyhow!("No input for day 5"))),
+        ];
+        let text = render(
+            Format::Text,
+            2025,
+            &results,
+            &KnownAnswers::default(),
+            ELAPSED,
+        );
+        assert!(text.ends_with("\nElapsed: 125.0ns\n"), "{text}");
+        assert!(!text.contains("CPU:"), "{text}");
+    }
+
+    #[test]
+    fn text_shows_one_day_as_a_block() {
+        let results = results();
+        let known = KnownAnswers::default();
+        let text = render(Format::Text, 2025, &results[..1], &known, ELAPSED);
+        assert!(text.starts_with("Day 3\n=="), "{text}");
+        let text = render(Format::Text, 2025, &results[1..2], &known, ELAPSED);
+        assert_eq!(text, "Day 4 failed: No input for day 4\n\n");
+    }
+
+    #[test]
+    fn format_names() -> anyhow::Result<()> {
+        assert_eq!("json".parse::<Format>()?, Format::Json);
+        assert_eq!("csv".parse::<Format>()?, Format::Csv);
+        assert_eq!("text".parse::<Format>()?, Format::Text);
+        assert_eq!("markdown".parse::<Format>()?, Format::Markdown);
+        assert!("yaml".parse::<Format>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn verification_summary() -> anyhow::Result<()> {
+        let known = KnownAnswers::parse("2025 3 1 357\n2025 3 2 1\n")?;
+        let expected = "\
+Day 3   part one: pass       part two: FAIL (got a \"quoted\", line, expected 1)
+Day 4   failed: No input for day 4
+Day 9   part one: unknown    part two: not run
+
+1 passed, 2 failed, 1 unknown
+";
+        assert_eq!(render_verification(2025, &results(), &known), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn inputs_side_by_side() -> anyhow::Result<()> {
+        let known = KnownAnswers::parse("2025 8/alice 1 40\n2025 8/alice 2 7\n2025 8/bob 1 3\n")?;
+        let results: Vec<InputResult> = vec![
+            (
+                "alice".to_owned(),
+                Ok(Answer::new(8, Parts::Both).first(|| 40).second(|| 7)),
+            ),
+            (
+                "bob".to_owned(),
+                Ok(Answer::new(8, Parts::Both).first(|| 12).second(|| 25272)),
+            ),
+            (
+                "carol".to_owned(),
+                Err(anyhow::anyhow!("No input for day 8")),
+            ),
+        ];
+        let expected = "\
+Day 8 of 2025, 3 input(s)
+
+Input  Part one                Part two
+alice  40 (verified)           7 (verified)
+bob    12 (WRONG, expected 3)  25272
+carol  failed: No input for day 8
+
+2 passed, 2 failed, 1 unknown
+";
+        assert_eq!(render_comparison(2025, 8, &results, &known), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn example_verdicts() {
+        let example = Example {
+            input: "",
+            part_one: Some("357"),
+            part_two: Some("3121910778619"),
+        };
+        let answer = || Answer::new(3, Parts::Both).first(|| 357).second(|| 1);
+        let results = vec![(3, Ok(vec![(example, answer())]))];
+        let text = render_examples(&results);
+        assert!(text.starts_with("Day 3\n"));
+        assert!(text.ends_with(
+            "Example 1: part one: pass       part two: FAIL (got 1, expected 3121910778619)\n\n"
+        ));
+        assert!(examples_failed(&results[0].1));
+        let unknown = Example {
+            part_two: None,
+            ..example
+        };
+        assert!(!examples_failed(&Ok(vec![(unknown, answer())])));
+        assert!(examples_failed(&Err(anyhow::anyhow!(
+            "Day 3 has no examples."
+        ))));
+    }
+}
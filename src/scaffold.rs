@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+/// Template for a new day's module, see [`render`].
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Where day modules live, in a directory per year, and are found by
+/// `build.rs`.
+pub fn days_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days")
+}
+
+/// The source of a new module for `year`'s `day`, with its parse and part
+/// functions and a test of the example to fill in.
+pub fn render(year: u16, day: usize, title: &str) -> String {
+    TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{NN}}", &format!("{day:02}"))
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{TITLE}}", &title.escape_default().to_string())
+}
+
+/// Write a new module for `year`'s `day` into its year's directory in `dir`,
+/// refusing to overwrite one.
+pub fn create(dir: &Path, year: u16, day: usize, title: &str) -> anyhow::Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        anyhow::bail!("Day must be between 1 and 25.");
+    }
+    let year_dir = dir.join(year.to_string());
+    let path = year_dir.join(format!("day{day:02}.rs"));
+    if path.exists() {
+        anyhow::bail!("{} already exists.", path.display());
+    }
+    std::fs::create_dir_all(&year_dir)
+        .with_context(|| format!("Failed to create {}", year_dir.display()))?;
+    std::fs::write(&path, render(year, day, title))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{create, render};
+    use crate::util::temp_dir;
+
+    #[test]
+    fn template_is_filled_in() {
+        let source = render(2025, 10, "Factory \"Floor\"");
+        assert!(source.contains("pub struct Day10;"));
+        assert!(source.contains("impl Solver for Day10 {"));
+        assert!(source.contains("const YEAR: u16 = 2025;"));
+        assert!(source.contains("const DAY: usize = 10;"));
+        assert!(source.contains(r#"const TITLE: &'static str = "Factory \"Floor\"";"#));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn existing_days_are_not_overwritten() -> anyhow::Result<()> {
+        let dir = temp_dir("scaffold");
+        let path = create(&dir, 2025, 3, "Lobby")?;
+        assert_eq!(path, dir.join("2025/day03.rs"));
+        assert!(create(&dir, 2025, 3, "Lobby").is_err());
+        assert!(create(&dir, 2024, 3, "Lobby").is_ok());
+        assert!(create(&dir, 2025, 26, "Too late").is_err());
+        Ok(())
+    }
+}
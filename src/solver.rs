@@ -0,0 +1,161 @@
+use crate::{
+    answers::Verdict,
+    util::{Answer, Parts, Value, thread_cpu_time},
+};
+
+/// A solution to one day's puzzle, split into its parse and part phases.
+pub trait Solver {
+    /// The year of the event this solves.
+    const YEAR: u16;
+    /// The day of the event this solves.
+    const DAY: usize;
+    /// The puzzle's title.
+    const TITLE: &'static str;
+    /// Example inputs from the puzzle text, with their expected answers.
+    const EXAMPLES: &'static [Example] = &[];
+    /// Whether spaces at the ends of lines matter, so they're kept however
+    /// the input is [normalized](crate::input::Normalize).
+    const SIGNIFICANT_SPACES: bool = false;
+
+    /// The parsed puzzle input, shared by both parts.
+    type Parsed;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed>;
+
+    /// Parse one of the [`EXAMPLES`](Self::EXAMPLES), for puzzles where the
+    /// example is solved with different parameters to the real input.
+    fn parse_example(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        self.parse(input)
+    }
+
+    fn part_one(&self, parsed: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>>;
+
+    /// Solve part two, or `None` if it hasn't been solved yet.
+    fn part_two(&self, _parsed: &mut Self::Parsed) -> Option<anyhow::Result<impl Into<Value>>> {
+        None::<anyhow::Result<Value>>
+    }
+}
+
+/// An example input from a puzzle's text, with the answers it should give.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_one: Option<&'static str>,
+    pub part_two: Option<&'static str>,
+}
+
+impl Example {
+    /// Compare both parts of an answer computed from this example.
+    pub fn check(&self, answer: &Answer) -> [Verdict; 2] {
+        [
+            Verdict::of(answer.part_one(), self.part_one),
+            Verdict::of(answer.part_two(), self.part_two),
+        ]
+    }
+}
+
+/// Object-safe view of a [`Solver`], so solvers for different days can share
+/// a registry. Implemented for every `Solver`.
+pub trait DynSolver: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> usize;
+    fn title(&self) -> &'static str;
+    fn examples(&self) -> &'static [Example];
+    fn significant_spaces(&self) -> bool;
+
+    /// Parse `input` and solve the requested parts, timing each phase.
+    fn solve(&self, input: &str, parts: Parts) -> anyhow::Result<Answer>;
+
+    /// Like [`solve`](Self::solve), but for one of the solver's examples.
+    fn solve_example(&self, example: &Example, parts: Parts) -> anyhow::Result<Answer>;
+}
+
+impl<S: Solver + Sync> DynSolver for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn significant_spaces(&self) -> bool {
+        S::SIGNIFICANT_SPACES
+    }
+
+    fn solve(&self, input: &str, parts: Parts) -> anyhow::Result<Answer> {
+        solve_with(self, parts, |solver| solver.parse(input))
+    }
+
+    fn solve_example(&self, example: &Example, parts: Parts) -> anyhow::Result<Answer> {
+        solve_with(self, parts, |solver| solver.parse_example(example.input))
+    }
+}
+
+fn solve_with<S: Solver>(
+    solver: &S,
+    parts: Parts,
+    parse: impl FnOnce(&S) -> anyhow::Result<S::Parsed>,
+) -> anyhow::Result<Answer> {
+    let _span = tracing::info_span!("solve", year = S::YEAR, day = S::DAY).entered();
+    let cpu_start = thread_cpu_time();
+    let mut answer = Answer::new(S::DAY, parts);
+    let mut parsed = answer.parse(|| tracing::info_span!("parse").in_scope(|| parse(solver)))?;
+    Ok(answer
+        .try_first(|| tracing::info_span!("part_one").in_scope(|| solver.part_one(&mut parsed)))?
+        .try_second(|| tracing::info_span!("part_two").in_scope(|| solver.part_two(&mut parsed)))?
+        .cpu_since(cpu_start))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DynSolver, Solver};
+    use crate::util::{Parts, Value};
+
+    struct Summer;
+
+    impl Solver for Summer {
+        const YEAR: u16 = 2025;
+        const DAY: usize = 42;
+        const TITLE: &'static str = "Summer";
+
+        type Parsed = Vec<u64>;
+
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+            Ok(input.split(',').map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(&self, parsed: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+            Ok(parsed.iter().sum::<u64>())
+        }
+    }
+
+    #[test]
+    fn phases_are_driven_generically() -> anyhow::Result<()> {
+        let solver: &dyn DynSolver = &Summer;
+        assert_eq!((solver.year(), solver.day()), (2025, 42));
+        assert_eq!(solver.title(), "Summer");
+        assert!(solver.examples().is_empty());
+
+        let answer = solver.solve("1,2,3", Parts::Both)?;
+        assert_eq!(answer.part_one(), Some(&Value::Int(6)));
+        // Part two isn't solved, so there's nothing to report.
+        assert_eq!(answer.part_two(), None);
+        assert!(answer.timings().parse.is_some());
+        assert!(answer.timings().part_two.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors_propagate() {
+        assert!(Summer.solve("1,two", Parts::Both).is_err());
+    }
+}
@@ -0,0 +1,466 @@
+use std::{
+    fmt::{Display, Write},
+    path::Path,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+
+use crate::client::Client;
+
+/// File, in the input directory, recording every answer submitted.
+pub const HISTORY_FILE: &str = "submissions.txt";
+
+/// How long the website makes you wait after a wrong answer.
+pub const RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// Wrong, with no hint about which way.
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the last answer, with how long was left.
+    RateLimited(Option<Duration>),
+    /// The part had already been solved, so the answer wasn't checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Read the outcome from the page returned for a submission.
+    pub fn from_response(page: &str) -> anyhow::Result<Self> {
+        Ok(if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::RateLimited(parse_wait(page))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            anyhow::bail!("Couldn't understand the response to the submission.");
+        })
+    }
+
+    /// Whether the answer was checked and found to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+/// Parse the wait from eg "You have 1m 5s left to wait".
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (before, _) = page.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+    let mut secs = 0;
+    for part in wait.split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too_high"),
+            Outcome::TooLow => write!(f, "too_low"),
+            Outcome::RateLimited(None) => write!(f, "rate_limited"),
+            Outcome::RateLimited(Some(wait)) => write!(f, "rate_limited:{}", wait.as_secs()),
+            Outcome::AlreadySolved => write!(f, "already_solved"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Outcome::Correct,
+            "wrong" => Outcome::Wrong,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "rate_limited" => Outcome::RateLimited(None),
+            "already_solved" => Outcome::AlreadySolved,
+            _ => match s.strip_prefix("rate_limited:").map(str::parse) {
+                Some(Ok(secs)) => Outcome::RateLimited(Some(Duration::from_secs(secs))),
+                _ => anyhow::bail!("Unknown outcome {s:?}"),
+            },
+        })
+    }
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// When it was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer submitted so far, oldest first.
+///
+/// The file format is one attempt per line as
+/// `time year day part outcome answer`, with `#` comments ignored.
+#[derive(Debug, Default)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Load the history from `path`, which may not exist yet.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).with_context(|| format!("In {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut attempts = Vec::new();
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.splitn(6, ' ').collect();
+            let &[time, year, day, part, outcome, answer] = fields.as_slice() else {
+                anyhow::bail!(
+                    "Line {}: expected `time year day part outcome answer`.",
+                    idx + 1
+                );
+            };
+            let context = || format!("Line {}", idx + 1);
+            attempts.push(Attempt {
+                time: time.parse().with_context(context)?,
+                year: year.parse().with_context(context)?,
+                day: day.parse().with_context(context)?,
+                part: part.parse().with_context(context)?,
+                answer: answer.to_owned(),
+                outcome: outcome.parse().with_context(context)?,
+            });
+        }
+        Ok(Self { attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Append `attempt` to the history file at `path`.
+    pub fn record(&mut self, path: &Path, attempt: Attempt) -> anyhow::Result<()> {
+        let mut line = String::new();
+        if !path.exists() {
+            line.push_str("# time year day part outcome answer\n");
+        }
+        writeln!(
+            line,
+            "{} {} {} {} {} {}",
+            attempt.time, attempt.year, attempt.day, attempt.part, attempt.outcome, attempt.answer
+        )
+        .unwrap();
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        std::io::Write::write_all(&mut file, line.as_bytes())
+            .with_context(|| format!("Failed to write to {}", path.display()))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Why `answer` shouldn't be submitted for `year`'s `day`'s `part` at
+    /// `now`, if the history already rules it out.
+    pub fn refusal(
+        &self,
+        (year, day, part): (u16, usize, u8),
+        answer: &str,
+        now: u64,
+    ) -> Option<String> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |a| (a.year, a.day, a.part) == (year, day, part))
+        };
+        if let Some(correct) = attempts().find(|a| a.outcome == Outcome::Correct) {
+            return Some(format!("Already solved with {}.", correct.answer));
+        }
+        if attempts().any(|a| a.answer == answer && a.outcome.is_wrong()) {
+            return Some(format!("{answer} was already submitted and is wrong."));
+        }
+        if let Ok(n) = answer.parse::<i128>() {
+            let bound = |outcome| {
+                attempts()
+                    .filter(move |a| a.outcome == outcome)
+                    .filter_map(|a| a.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Outcome::TooHigh).min()
+                && n >= high
+            {
+                return Some(format!("{answer} is too high, {high} already was."));
+            }
+            if let Some(low) = bound(Outcome::TooLow).max()
+                && n <= low
+            {
+                return Some(format!("{answer} is too low, {low} already was."));
+            }
+        }
+        let wait = self.wait(now);
+        if !wait.is_zero() {
+            return Some(format!("Wait {}s before submitting again.", wait.as_secs()));
+        }
+        None
+    }
+
+    /// How long until the website will accept another answer.
+    fn wait(&self, now: u64) -> Duration {
+        let Some(last) = self.attempts.last() else {
+            return Duration::ZERO;
+        };
+        let delay = match last.outcome {
+            Outcome::RateLimited(wait) => wait.unwrap_or(RETRY_DELAY),
+            outcome if outcome.is_wrong() => RETRY_DELAY,
+            _ => return Duration::ZERO,
+        };
+        let elapsed = Duration::from_secs(now.saturating_sub(last.time));
+        delay.saturating_sub(elapsed)
+    }
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Submit `answer` for `year`'s `day`'s `part`, unless the history at
+/// `history_path` rules it out, and record the outcome there.
+pub fn submit(
+    client: &Client,
+    history_path: &Path,
+    (year, day, part): (u16, usize, u8),
+    answer: &str,
+) -> anyhow::Result<Outcome> {
+    // The history holds one answer per line, after a space.
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        anyhow::bail!("Not submitting {answer:?}: answers must be a single word.");
+    }
+    let mut history = History::load(history_path)?;
+    let now = now();
+    if let Some(reason) = history.refusal((year, day, part), answer, now) {
+        anyhow::bail!("Not submitting {year} day {day} part {part}: {reason}");
+    }
+    // Make sure the outcome can be recorded before sending the answer.
+    if let Some(dir) = history_path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    let outcome = Outcome::from_response(&client.post_answer(year, day, part, answer)?)?;
+    let attempt = Attempt {
+        time: now,
+        year,
+        day,
+        part,
+        answer: answer.to_owned(),
+        outcome,
+    };
+    history.record(history_path, attempt)?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{History, Outcome, submit};
+    use crate::{
+        client::{Client, stub},
+        util::temp_dir,
+    };
+
+    #[test]
+    fn outcomes_from_responses() -> anyhow::Result<()> {
+        let page = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, ...",
+                Outcome::Wrong,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait.",
+                Outcome::RateLimited(Some(Duration::from_secs(65))),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Outcome::AlreadySolved,
+            ),
+        ];
+        for (text, outcome) in cases {
+            assert_eq!(Outcome::from_response(&page(text))?, outcome);
+            assert_eq!(outcome.to_string().parse::<Outcome>()?, outcome);
+        }
+        assert!(Outcome::from_response("<html>Log in</html>").is_err());
+        Ok(())
+    }
+
+    static HISTORY: &str = "\
+# time year day part outcome answer
+1000 2025 9 2 too_high 500
+1100 2025 9 2 too_low 100
+1200 2025 9 2 wrong 250
+1300 2025 9 1 correct 50
+";
+
+    #[test]
+    fn history_rules_out_answers() -> anyhow::Result<()> {
+        let history = History::parse(HISTORY)?;
+        assert_eq!(history.attempts().len(), 4);
+        let later = 2000;
+        assert!(
+            history
+                .refusal((2025, 9, 1), "51", later)
+                .unwrap()
+                .starts_with("Already solved")
+        );
+        assert!(
+            history
+                .refusal((2025, 9, 2), "250", later)
+                .unwrap()
+                .contains("is wrong")
+        );
+        assert!(
+            history
+                .refusal((2025, 9, 2), "501", later)
+                .unwrap()
+                .contains("too high")
+        );
+        assert!(
+            history
+                .refusal((2025, 9, 2), "600", later)
+                .unwrap()
+                .contains("too high")
+        );
+        assert!(
+            history
+                .refusal((2025, 9, 2), "99", later)
+                .unwrap()
+                .contains("too low")
+        );
+        assert_eq!(history.refusal((2025, 9, 2), "300", later), None);
+        // Bounds only apply to the part they were recorded for.
+        assert_eq!(history.refusal((2025, 8, 2), "600", later), None);
+        // Or the year.
+        assert_eq!(history.refusal((2024, 9, 1), "51", later), None);
+        Ok(())
+    }
+
+    #[test]
+    fn submissions_are_throttled() -> anyhow::Result<()> {
+        let history = History::parse("1000 2025 9 2 wrong 250\n")?;
+        assert!(
+            history
+                .refusal((2025, 9, 2), "300", 1030)
+                .unwrap()
+                .starts_with("Wait 30s")
+        );
+        assert_eq!(history.refusal((2025, 9, 2), "300", 1060), None);
+        let history = History::parse("1000 2025 9 2 rate_limited:90 250\n")?;
+        assert!(
+            history
+                .refusal((2025, 3, 1), "7", 1060)
+                .unwrap()
+                .starts_with("Wait 30s")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn submit_posts_and_records() -> anyhow::Result<()> {
+        let (url, requests) = stub::serve(|_| {
+            (
+                200,
+                "<p>That's not the right answer; your answer is too low.</p>".to_owned(),
+            )
+        });
+        let client = Client::new(&url, Some("abc123".to_owned()));
+        let path = temp_dir("submit").join(super::HISTORY_FILE);
+
+        assert_eq!(
+            submit(&client, &path, (2025, 9, 2), "1234")?,
+            Outcome::TooLow
+        );
+        let history = History::load(&path)?;
+        let [attempt] = history.attempts() else {
+            panic!("Expected one attempt, got {:?}", history.attempts());
+        };
+        assert_eq!((attempt.day, attempt.part), (9, 2));
+        assert_eq!(attempt.answer, "1234");
+        assert_eq!(attempt.outcome, Outcome::TooLow);
+        // Resubmitting is refused without contacting the website.
+        assert!(submit(&client, &path, (2025, 9, 2), "1234").is_err());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].request_line, "POST /2025/day/9/answer HTTP/1.1");
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+        Ok(())
+    }
+
+    #[test]
+    fn history_directory_is_created() -> anyhow::Result<()> {
+        let (url, requests) = stub::serve(|_| (200, "<p>That's the right answer!</p>".to_owned()));
+        let client = Client::new(&url, Some("abc123".to_owned()));
+        let path = temp_dir("submit-dir")
+            .join("input")
+            .join(super::HISTORY_FILE);
+
+        assert_eq!(
+            submit(&client, &path, (2025, 1, 1), "42")?,
+            Outcome::Correct
+        );
+        assert_eq!(History::load(&path)?.attempts().len(), 1);
+        // Answers that wouldn't fit on one line aren't sent.
+        assert!(submit(&client, &path, (2025, 1, 2), "1 2").is_err());
+        assert!(submit(&client, &path, (2025, 1, 2), "#.\n.#").is_err());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use std::path::Path;
+
+use anyhow::Context;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::{EnvFilter, layer::SubscriberExt};
+
+/// What's logged and traced when neither `--log` nor `RUST_LOG` is given,
+/// which includes the spans around each day and its phases.
+const DEFAULT_FILTER: &str = "info";
+
+/// Build a subscriber that logs to stdout and, if `chrome` is given, writes
+/// a Chrome trace-event file there (for `chrome://tracing` or Perfetto).
+///
+/// `filter` takes the same directives as `RUST_LOG`, which is used instead
+/// when it's `None`. The trace file is complete once the returned guard is
+/// dropped.
+pub fn subscriber(
+    filter: Option<&str>,
+    chrome: Option<&Path>,
+) -> anyhow::Result<(impl tracing::Subscriber + Send + Sync, Option<FlushGuard>)> {
+    let from_env = std::env::var(EnvFilter::DEFAULT_ENV).ok();
+    let directives = filter.or(from_env.as_deref()).unwrap_or(DEFAULT_FILTER);
+    let filter =
+        EnvFilter::try_new(directives).with_context(|| format!("Bad log filter {directives:?}"))?;
+
+    let (trace, guard) = match chrome {
+        Some(path) => {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Failed to create {}", path.display()))?;
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+    let subscriber = tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(trace);
+    Ok((subscriber, guard))
+}
+
+/// Install the [`subscriber`] for the rest of the program, returning the
+/// guard to hold until it exits.
+pub fn init(filter: Option<&str>, chrome: Option<&Path>) -> anyhow::Result<Option<FlushGuard>> {
+    let (subscriber, guard) = subscriber(filter, chrome)?;
+    tracing::subscriber::set_global_default(subscriber)
+        .context("Failed to install the tracing subscriber")?;
+    Ok(guard)
+}
+
+#[cfg(test)]
+mod test {
+    use super::subscriber;
+    use crate::{Parts, util::temp_dir};
+
+    #[test]
+    fn phases_are_written_to_a_chrome_trace() -> anyhow::Result<()> {
+        let path = temp_dir("trace").join("trace.json");
+        let (subscriber, guard) = subscriber(Some("info"), Some(&path))?;
+        tracing::subscriber::with_default(subscriber, || {
+            crate::run_examples(2025, 1, Parts::Both)
+        })?;
+        drop(guard);
+
+        let trace = std::fs::read_to_string(&path)?;
+        assert!(trace.starts_with('['), "{trace}");
+        for span in ["run_examples", "solve", "parse", "part_one", "part_two"] {
+            assert!(trace.contains(&format!("\"name\":\"{span}\"")), "{span}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn bad_filters_are_rejected() {
+        assert!(subscriber(Some("aoc_2025=loud"), None).is_err());
+    }
+}
@@ -1,48 +1,313 @@
-use std::fmt::Display;
+use std::{
+    fmt::{Display, Write},
+    time::{Duration, Instant},
+};
 
-pub struct Answer {
-    day: usize,
-    first: String,
-    second: String,
+use crate::answers::Verdict;
+
+pub mod geometry;
+mod grid;
+mod interval;
+
+pub use grid::Grid;
+pub use interval::{Discrete, IntervalSet};
+
+/// Which parts of a day's puzzle to solve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[default]
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    pub fn includes_one(self) -> bool {
+        matches!(self, Parts::Both | Parts::One)
+    }
+
+    pub fn includes_two(self) -> bool {
+        matches!(self, Parts::Both | Parts::Two)
+    }
+}
+
+/// Wall-clock time spent in each phase of solving a day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+    /// CPU time used solving the day, where the platform reports it. This is
+    /// less than the wall time when the thread wasn't always running.
+    pub cpu: Option<Duration>,
+}
+
+impl Timings {
+    /// Total wall-clock time across the phases.
+    pub fn total(&self) -> Duration {
+        [self.parse, self.part_one, self.part_two]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// CPU time used by the calling thread so far.
+#[cfg(unix)]
+pub fn thread_cpu_time() -> Option<Duration> {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid timespec for the call to write to.
+    let status = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time) };
+    (status == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+/// CPU time used by the calling thread so far, which isn't reported here.
+#[cfg(not(unix))]
+pub fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+/// One part's answer, kept as the type it was computed as until it's shown.
+#[derive(Debug, Clone, Eq)]
+pub enum Value {
+    /// An integer that fits in an `i64`.
+    Int(i64),
+    /// An integer too big for an `i64`.
+    BigInt(i128),
+    /// A single line of text.
+    Text(String),
+    /// Several lines of text, eg letters drawn on a grid.
+    Lines(Vec<String>),
+}
+
+impl Value {
+    /// The value as an integer, if it is one.
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Value::Int(n) => Some(i128::from(*n)),
+            Value::BigInt(n) => Some(*n),
+            Value::Text(_) | Value::Lines(_) => None,
+        }
+    }
+
+    /// The value as a single line of text, if it is one.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Value::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The lines of a multi-line value.
+    pub fn lines(&self) -> Option<&[String]> {
+        match self {
+            Value::Lines(lines) => Some(lines),
+            _ => None,
+        }
+    }
+
+    /// Whether this is the `expected` answer, as written in the known answers
+    /// file or an example. Integers are compared by value, text as written.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self.as_int() {
+            Some(n) => expected.trim().parse() == Ok(n),
+            None => self.to_string() == expected,
+        }
+    }
 }
-pub struct PartialAnswer {
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_int(), other.as_int()) {
+            (Some(a), Some(b)) => a == b,
+            _ => match (self, other) {
+                (Value::Text(a), Value::Text(b)) => a == b,
+                (Value::Lines(a), Value::Lines(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(n) => Display::fmt(n, f),
+            Value::BigInt(n) => Display::fmt(n, f),
+            Value::Text(s) => f.pad(s),
+            Value::Lines(lines) => f.pad(&lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_small_int {
+    ($($t:ty)+) => {
+        $(
+            impl From<$t> for Value {
+                fn from(n: $t) -> Self {
+                    Value::Int(n.into())
+                }
+            }
+        )+
+    };
+}
+
+impl_from_small_int!(i8 i16 i32 i64 u8 u16 u32);
+
+macro_rules! impl_from_big_int {
+    ($($t:ty)+) => {
+        $(
+            impl From<$t> for Value {
+                fn from(n: $t) -> Self {
+                    i64::try_from(n).map_or(Value::BigInt(n as i128), Value::Int)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_big_int!(u64 usize isize i128);
+
+impl From<u128> for Value {
+    fn from(n: u128) -> Self {
+        // Only the very largest don't fit in an `i128`.
+        i128::try_from(n).map_or_else(|_| Value::Text(n.to_string()), Value::from)
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_owned())
+    }
+}
+
+impl From<Vec<String>> for Value {
+    fn from(lines: Vec<String>) -> Self {
+        Value::Lines(lines)
+    }
+}
+
+/// The answers computed for a day, holding only the parts that were requested.
+#[derive(Debug)]
+pub struct Answer {
     day: usize,
-    first: String,
+    parts: Parts,
+    first: Option<Value>,
+    second: Option<Value>,
+    timings: Timings,
 }
 
 impl Answer {
-    pub fn first<T: Display>(day: usize, answer: T) -> PartialAnswer {
-        PartialAnswer {
+    pub fn new(day: usize, parts: Parts) -> Self {
+        Self {
             day,
-            first: answer.to_string(),
+            parts,
+            first: None,
+            second: None,
+            timings: Timings::default(),
         }
     }
 
-    pub fn report(self) -> anyhow::Result<String> {
-        Ok(self.to_string())
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    pub fn part_one(&self) -> Option<&Value> {
+        self.first.as_ref()
+    }
+
+    pub fn part_two(&self) -> Option<&Value> {
+        self.second.as_ref()
+    }
+
+    pub fn timings(&self) -> &Timings {
+        &self.timings
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_timings(mut self, timings: Timings) -> Self {
+        self.timings = timings;
+        self
+    }
+
+    /// Record the CPU time used since `start`, a [`thread_cpu_time`] taken
+    /// on the same thread before solving.
+    pub fn cpu_since(mut self, start: Option<Duration>) -> Self {
+        self.timings.cpu = start
+            .zip(thread_cpu_time())
+            .map(|(start, now)| now.saturating_sub(start));
+        self
     }
-}
 
-impl PartialAnswer {
-    pub fn second<T: Display>(self, answer: T) -> Answer {
-        Answer {
-            day: self.day,
-            first: self.first,
-            second: answer.to_string(),
+    /// Run the parse phase, recording how long it took.
+    pub fn parse<T>(&mut self, f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+        let (parsed, elapsed) = timed(f);
+        self.timings.parse = Some(elapsed);
+        parsed
+    }
+
+    /// Record part one, only calling `f` if part one was requested.
+    pub fn first<T: Into<Value>>(mut self, f: impl FnOnce() -> T) -> Self {
+        if self.parts.includes_one() {
+            let (answer, elapsed) = timed(f);
+            self.first = Some(answer.into());
+            self.timings.part_one = Some(elapsed);
         }
+        self
     }
 
-    #[allow(dead_code)]
-    pub fn report(self) -> anyhow::Result<String> {
-        Ok(self.to_string())
+    /// Record part one from a fallible `f`, only called if part one was requested.
+    pub fn try_first<T: Into<Value>>(
+        mut self,
+        f: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<Self> {
+        if self.parts.includes_one() {
+            let (answer, elapsed) = timed(f);
+            self.first = Some(answer?.into());
+            self.timings.part_one = Some(elapsed);
+        }
+        Ok(self)
     }
-}
 
-impl Display for PartialAnswer {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Day {}", self.day)?;
-        writeln!(f, "==========================")?;
-        writeln!(f, "Part one: {:>16}", self.first)
+    /// Record part two, only calling `f` if part two was requested.
+    pub fn second<T: Into<Value>>(mut self, f: impl FnOnce() -> T) -> Self {
+        if self.parts.includes_two() {
+            let (answer, elapsed) = timed(f);
+            self.second = Some(answer.into());
+            self.timings.part_two = Some(elapsed);
+        }
+        self
+    }
+
+    /// Record part two from a fallible `f`, only called if part two was
+    /// requested. `f` returns `None` when part two hasn't been solved yet.
+    pub fn try_second<T: Into<Value>>(
+        mut self,
+        f: impl FnOnce() -> Option<anyhow::Result<T>>,
+    ) -> anyhow::Result<Self> {
+        if self.parts.includes_two() {
+            let (answer, elapsed) = timed(f);
+            if let Some(answer) = answer {
+                self.second = Some(answer?.into());
+                self.timings.part_two = Some(elapsed);
+            }
+        }
+        Ok(self)
     }
 }
 
@@ -50,30 +315,405 @@ impl Display for Answer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Day {}", self.day)?;
         writeln!(f, "==========================")?;
-        writeln!(f, "Part one: {:>16}", self.first)?;
-        writeln!(f, "Part two: {:>16}", self.second)
+        if let Some(parse) = self.timings.parse {
+            writeln!(f, "Parse:    {:>16}  {parse:>10.1?}", "")?;
+        }
+        for (label, answer, time) in [
+            ("Part one:", &self.first, self.timings.part_one),
+            ("Part two:", &self.second, self.timings.part_two),
+        ] {
+            match (answer, time) {
+                // Lines can't be right-aligned, so they go underneath.
+                (Some(Value::Lines(lines)), Some(time)) => {
+                    writeln!(f, "{label} {:>16}  {time:>10.1?}", "")?;
+                    for line in lines {
+                        writeln!(f, "  {line}")?;
+                    }
+                }
+                (Some(answer), Some(time)) => writeln!(f, "{label} {answer:>16}  {time:>10.1?}")?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How [`render_table`] lays out its table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TableStyle {
+    /// Aligned columns for the terminal.
+    #[default]
+    Text,
+    /// A GitHub-flavoured Markdown table, eg for the README.
+    Markdown,
+}
+
+/// One day's row in a [`render_table`].
+pub struct TableRow<'a> {
+    pub day: usize,
+    pub result: &'a anyhow::Result<Answer>,
+    /// Each part checked against the known answers.
+    pub verdicts: [Verdict; 2],
+}
+
+/// One cell of a [`render_table`], and the lines that go under its row.
+struct Cell {
+    text: String,
+    failed: bool,
+    below: Vec<String>,
+}
+
+impl Cell {
+    fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            failed: false,
+            below: Vec::new(),
+        }
+    }
+
+    fn failed(mut self) -> Self {
+        self.failed = true;
+        self
+    }
+
+    /// A cell whose `lines` can't fit on one line of text, so in
+    /// [`TableStyle::Text`] they go under the row and `summary` stands in.
+    fn multiline(summary: &str, lines: &[String], style: TableStyle) -> Self {
+        match style {
+            TableStyle::Text => Self {
+                below: lines.to_vec(),
+                ..Self::new(summary)
+            },
+            TableStyle::Markdown => Self::new(lines.join("<br>")),
+        }
+    }
+
+    fn answer(value: Option<&Value>, verdict: &Verdict, style: TableStyle) -> Self {
+        let Some(value) = value else {
+            return Self::new("-");
+        };
+        let cell = match value {
+            Value::Lines(lines) => Self::multiline("(below)", lines, style),
+            value => Self::new(value.to_string()),
+        };
+        match verdict {
+            Verdict::Fail { expected, .. } => Self {
+                text: format!("{} (WRONG, expected {expected})", cell.text),
+                ..cell
+            }
+            .failed(),
+            _ => cell,
+        }
+    }
+
+    /// The cell's text as it appears in the table, with failures in bold for
+    /// Markdown. Failures in text already stand out by being in capitals.
+    fn render(&self, style: TableStyle) -> String {
+        match style {
+            TableStyle::Text => self.text.clone(),
+            TableStyle::Markdown => {
+                let text = self.text.replace('|', "\\|");
+                if self.failed && !text.is_empty() {
+                    format!("**{text}**")
+                } else {
+                    text
+                }
+            }
+        }
     }
 }
 
+/// Render one aligned table of the answers for each day, how long it took
+/// and whether its answers are verified, followed by the total time.
+///
+/// Columns are as wide as their widest cell, so long answers don't push the
+/// rest out of line. Wrong answers and failed days are highlighted, and
+/// answers drawn over several lines are shown under their day's row in text.
+pub fn render_table(rows: &[TableRow], style: TableStyle) -> String {
+    const HEADER: [&str; 5] = ["Day", "Part one", "Part two", "Time", "Verified"];
+    let mut table: Vec<[Cell; 5]> = Vec::new();
+    let mut total = Duration::ZERO;
+    for row in rows {
+        let cells = match row.result {
+            Ok(answer) => {
+                let time = answer.timings().total();
+                total += time;
+                let [one, two] = &row.verdicts;
+                let verified = if row.verdicts.iter().any(Verdict::is_fail) {
+                    Cell::new("FAIL").failed()
+                } else if row
+                    .verdicts
+                    .iter()
+                    .all(|v| matches!(v, Verdict::Pass | Verdict::NotRun))
+                    && row.verdicts.contains(&Verdict::Pass)
+                {
+                    Cell::new("yes")
+                } else {
+                    Cell::new("-")
+                };
+                [
+                    Cell::new(row.day.to_string()),
+                    Cell::answer(answer.part_one(), one, style),
+                    Cell::answer(answer.part_two(), two, style),
+                    Cell::new(format!("{time:.1?}")),
+                    verified,
+                ]
+            }
+            Err(e) => {
+                let lines: Vec<_> = format!("{e:#}").lines().map(str::to_owned).collect();
+                [
+                    Cell::new(row.day.to_string()).failed(),
+                    Cell::multiline("FAILED", &lines, style).failed(),
+                    Cell::new("-"),
+                    Cell::new("-"),
+                    Cell::new("FAIL").failed(),
+                ]
+            }
+        };
+        table.push(cells);
+    }
+    table.push([
+        Cell::new("Total"),
+        Cell::new(""),
+        Cell::new(""),
+        Cell::new(format!("{total:.1?}")),
+        Cell::new(""),
+    ]);
+
+    let rendered: Vec<[String; 5]> = table
+        .iter()
+        .map(|cells| cells.each_ref().map(|cell| cell.render(style)))
+        .collect();
+    let mut widths = HEADER.map(|header| header.chars().count());
+    for cells in &rendered {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    // Answers and times are right-aligned so that their digits line up.
+    let right = [false, true, true, true, false];
+    let line = |cells: [&str; 5]| {
+        let cells: Vec<_> = (0..5)
+            .map(|idx| {
+                let (width, cell) = (widths[idx], cells[idx]);
+                if right[idx] {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect();
+        match style {
+            TableStyle::Text => cells.join("  ").trim_end().to_owned(),
+            TableStyle::Markdown => format!("| {} |", cells.join(" | ")),
+        }
+    };
+
+    let mut out = String::new();
+    writeln!(out, "{}", line(HEADER)).unwrap();
+    if style == TableStyle::Markdown {
+        let rules: Vec<_> = (0..5)
+            .map(|idx| {
+                let dashes = "-".repeat(widths[idx] - 1);
+                if right[idx] {
+                    format!("{dashes}:")
+                } else {
+                    format!(":{dashes}")
+                }
+            })
+            .collect();
+        writeln!(out, "| {} |", rules.join(" | ")).unwrap();
+    }
+    for (cells, rendered) in table.iter().zip(&rendered) {
+        writeln!(out, "{}", line(rendered.each_ref().map(String::as_str))).unwrap();
+        for below in cells.iter().flat_map(|cell| &cell.below) {
+            writeln!(out, "  {below}").unwrap();
+        }
+    }
+    out
+}
+
 #[rstest::fixture]
 #[once]
 fn tracing_fixture() -> () {
     tracing_subscriber::fmt::init();
 }
 
-pub fn transpose<T>(rows: Vec<Vec<T>>) -> Vec<Vec<T>> {
-    let Some(n_columns) = rows.first().map(Vec::len) else {
-        // Rows is empty
-        return rows;
-    };
-    let mut iterators: Vec<_> = rows.into_iter().map(|row| row.into_iter()).collect();
-    (0..n_columns)
-        .map(|_| {
-            // For each column, pop a number from each row (ie, take a column at a time).
-            iterators
-                .iter_mut()
-                .map(|row| row.next().unwrap())
-                .collect()
+/// An empty directory for a test's files.
+#[cfg(test)]
+pub(crate) fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_2025-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Answer, Parts, TableRow, TableStyle, Timings, Value, render_table};
+    use crate::answers::Verdict;
+
+    fn timed(answer: Answer, nanos: u64) -> Answer {
+        answer.with_timings(Timings {
+            part_one: Some(Duration::from_nanos(nanos)),
+            ..Timings::default()
         })
-        .collect()
+    }
+
+    #[test]
+    fn answer_reports_both_parts() {
+        let answer = Answer::new(3, Parts::Both)
+            .first(|| 357)
+            .second(|| 3121910778619u64);
+        let report = answer.to_string();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines[0], "Day 3");
+        assert!(lines[2].starts_with("Part one:              357  "));
+        assert!(lines[3].starts_with("Part two:    3121910778619  "));
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[test]
+    fn values_keep_their_type() {
+        let answer = Answer::new(10, Parts::Both)
+            .first(|| u64::MAX)
+            .second(|| vec!["#..#".to_owned(), "####".to_owned()]);
+        assert_eq!(answer.part_one(), Some(&Value::BigInt(u64::MAX.into())));
+        assert_eq!(
+            answer.part_one().and_then(Value::as_int),
+            Some(u64::MAX.into())
+        );
+        assert_eq!(
+            answer.part_two().and_then(Value::lines).map(<[_]>::len),
+            Some(2)
+        );
+        // Integers are equal whichever variant holds them.
+        assert_eq!(Value::from(7u64), Value::BigInt(7));
+        assert_ne!(Value::from(7), Value::from("7"));
+        assert_eq!(Value::from(-3).to_string(), "-3");
+
+        let report = answer.to_string();
+        assert!(report.contains("Part one: 18446744073709551615"));
+        assert!(report.ends_with("\n  #..#\n  ####\n"), "{report}");
+    }
+
+    #[test]
+    fn values_match_expected_answers() {
+        assert!(Value::from(357).matches("357"));
+        assert!(Value::from(357).matches("0357"));
+        assert!(!Value::from(357).matches("358"));
+        assert!(Value::from("abc").matches("abc"));
+        assert!(!Value::from("abc").matches("abc "));
+        assert!(Value::from(vec!["a".to_owned(), "b".to_owned()]).matches("a\nb"));
+    }
+
+    #[test]
+    fn table_columns_fit_wide_answers() {
+        let wide = Ok(timed(Answer::new(1, Parts::One).first(|| u128::MAX), 5));
+        let letters = Ok(timed(
+            Answer::new(2, Parts::Both)
+                .first(|| 7)
+                .second(|| vec!["#..#".to_owned(), "####".to_owned()]),
+            9,
+        ));
+        let rows = [
+            TableRow {
+                day: 1,
+                result: &wide,
+                verdicts: [Verdict::Unknown, Verdict::NotRun],
+            },
+            TableRow {
+                day: 2,
+                result: &letters,
+                verdicts: [Verdict::Pass, Verdict::Unknown],
+            },
+        ];
+        let expected = "\
+Day                                   Part one  Part two    Time  Verified
+1      340282366920938463463374607431768211455         -   5.0ns  -
+2                                            7   (below)   9.0ns  -
+  #..#
+  ####
+Total                                                     14.0ns
+";
+        assert_eq!(render_table(&rows, TableStyle::Text), expected);
+    }
+
+    #[test]
+    fn markdown_tables_highlight_failures() {
+        let wrong = Ok(timed(Answer::new(5, Parts::One).first(|| "a|b"), 3));
+        let failed = Err(anyhow::anyhow!("line 1, column 2: oops\n  | x"));
+        let rows = [
+            TableRow {
+                day: 5,
+                result: &wrong,
+                verdicts: [
+                    Verdict::Fail {
+                        expected: "c".to_owned(),
+                        actual: "a|b".to_owned(),
+                    },
+                    Verdict::NotRun,
+                ],
+            },
+            TableRow {
+                day: 6,
+                result: &failed,
+                verdicts: [Verdict::NotRun, Verdict::NotRun],
+            },
+        ];
+        let expected = "\
+| Day   |                             Part one | Part two |  Time | Verified |
+| :---- | -----------------------------------: | -------: | ----: | :------- |
+| 5     |         **a\\|b (WRONG, expected c)** |        - | 3.0ns | **FAIL** |
+| **6** | **line 1, column 2: oops<br>  \\| x** |        - |     - | **FAIL** |
+| Total |                                      |          | 3.0ns |          |
+";
+        assert_eq!(render_table(&rows, TableStyle::Markdown), expected);
+    }
+
+    #[test]
+    fn phases_are_timed() -> anyhow::Result<()> {
+        let mut answer = Answer::new(1, Parts::One);
+        let parsed = answer.parse(|| Ok(vec![1, 2, 3]))?;
+        let answer = answer
+            .first(|| parsed.iter().sum::<u64>())
+            .second(|| parsed.len());
+        let timings = answer.timings();
+        assert!(timings.parse.is_some());
+        assert!(timings.part_one.is_some());
+        assert!(timings.part_two.is_none());
+        assert_eq!(
+            timings.total(),
+            timings.parse.unwrap() + timings.part_one.unwrap()
+        );
+        assert!(
+            answer
+                .to_string()
+                .lines()
+                .nth(2)
+                .unwrap()
+                .starts_with("Parse:")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unrequested_parts_are_not_computed() {
+        let answer = Answer::new(3, Parts::Two)
+            .first(|| -> u64 { panic!("part one should not run") })
+            .second(|| 40);
+        assert!(answer.first.is_none());
+        assert!(!answer.to_string().contains("Part one"));
+        assert!(answer.to_string().contains("Part two:"));
+    }
+
+    #[test]
+    fn fallible_part_errors_propagate() {
+        let result = Answer::new(8, Parts::One)
+            .try_first(|| -> anyhow::Result<u64> { anyhow::bail!("Could only connect 3") });
+        assert!(result.is_err());
+    }
 }
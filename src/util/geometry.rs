@@ -0,0 +1,254 @@
+//! Integer points on a plane or in space, which double as the vectors
+//! between them, and the boxes that bound them.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use nom::{
+    IResult, Parser,
+    character::complete::{char, i64},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// What [`Bounds`] needs from the points it holds.
+pub trait Point: Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// The point with every coordinate 1.
+    const ONE: Self;
+
+    /// The smaller of each coordinate of `self` and `other`.
+    fn min_each(self, other: Self) -> Self;
+
+    /// The larger of each coordinate of `self` and `other`.
+    fn max_each(self, other: Self) -> Self;
+
+    /// Whether no coordinate is greater than `other`'s.
+    fn all_le(self, other: Self) -> bool;
+}
+
+/// The distances and vector arithmetic shared by points of each dimension.
+macro_rules! impl_point {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl Point for $point {
+            const ONE: Self = Self { $($axis: 1),+ };
+
+            fn min_each(self, other: Self) -> Self {
+                Self { $($axis: self.$axis.min(other.$axis)),+ }
+            }
+
+            fn max_each(self, other: Self) -> Self {
+                Self { $($axis: self.$axis.max(other.$axis)),+ }
+            }
+
+            fn all_le(self, other: Self) -> bool {
+                true $(&& self.$axis <= other.$axis)+
+            }
+        }
+
+        impl $point {
+            /// The distance moving along one axis at a time, as on a grid.
+            pub fn manhattan(&self, other: &Self) -> u64 {
+                0u64 $(+ self.$axis.abs_diff(other.$axis))+
+            }
+
+            /// The distance moving diagonally as well, as a king does.
+            pub fn chebyshev(&self, other: &Self) -> u64 {
+                0u64 $(.max(self.$axis.abs_diff(other.$axis)))+
+            }
+
+            /// The square of the straight-line distance, which orders points
+            /// the same way without leaving the integers.
+            pub fn squared_distance(&self, other: &Self) -> u64 {
+                0u64 $(+ self.$axis.abs_diff(other.$axis).pow(2))+
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, scale: i64) -> Self {
+                Self { $($axis: self.$axis * scale),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+/// The smallest axis-aligned box holding some points, including its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: Point> Bounds<P> {
+    /// The box with `a` and `b` at opposite corners.
+    pub fn spanning(a: P, b: P) -> Self {
+        Self {
+            min: a.min_each(b),
+            max: a.max_each(b),
+        }
+    }
+
+    /// The box around all of `points`, or `None` if there aren't any.
+    pub fn of(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(
+            points.fold(Self::spanning(first, first), |bounds, point| Self {
+                min: bounds.min.min_each(point),
+                max: bounds.max.max_each(point),
+            }),
+        )
+    }
+
+    pub fn contains(&self, point: &P) -> bool {
+        self.min.all_le(*point) && point.all_le(self.max)
+    }
+
+    /// The number of integer points along each axis.
+    pub fn size(&self) -> P {
+        self.max - self.min + P::ONE
+    }
+}
+
+impl Bounds<Point2> {
+    /// The number of integer points in the rectangle, edges included.
+    pub fn area(&self) -> u64 {
+        let size = self.size();
+        size.x.unsigned_abs() * size.y.unsigned_abs()
+    }
+}
+
+impl Bounds<Point3> {
+    /// The number of integer points in the box, faces included.
+    pub fn volume(&self) -> u64 {
+        let size = self.size();
+        size.x.unsigned_abs() * size.y.unsigned_abs() * size.z.unsigned_abs()
+    }
+}
+
+/// Parse a point written `x,y`.
+pub fn point2(input: &str) -> IResult<&str, Point2> {
+    let (rest, (x, _, y)) = (i64, char(','), i64).parse(input)?;
+    Ok((rest, Point2::new(x, y)))
+}
+
+/// Parse a point written `x,y,z`.
+pub fn point3(input: &str) -> IResult<&str, Point3> {
+    let (rest, (x, _, y, _, z)) = (i64, char(','), i64, char(','), i64).parse(input)?;
+    Ok((rest, Point3::new(x, y, z)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bounds, Point2, Point3, point2, point3};
+    use crate::parse::parse_all;
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point3::new(1, -2, 3), Point3::new(4, 2, 3));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.squared_distance(&b), 25);
+        assert_eq!(b.squared_distance(&a), 25);
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let mut p = Point2::new(2, 3);
+        assert_eq!(p + Point2::new(1, -1), Point2::new(3, 2));
+        assert_eq!(p - Point2::new(5, 5), Point2::new(-3, -2));
+        assert_eq!(-p * 2, Point2::new(-4, -6));
+        p += Point2::new(1, 1);
+        p -= Point2::new(0, 2);
+        assert_eq!(p, Point2::new(3, 2));
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let rectangle = Bounds::spanning(Point2::new(11, 1), Point2::new(2, 5));
+        assert_eq!(rectangle.min, Point2::new(2, 1));
+        assert_eq!(rectangle.area(), 50);
+        assert!(rectangle.contains(&Point2::new(11, 5)));
+        assert!(!rectangle.contains(&Point2::new(12, 5)));
+
+        let points = [(1, 2, 3), (-1, 5, 3), (0, 0, 4)].map(|(x, y, z)| Point3::new(x, y, z));
+        let bounds = Bounds::of(points).unwrap();
+        assert_eq!(bounds.min, Point3::new(-1, 0, 3));
+        assert_eq!(bounds.max, Point3::new(1, 5, 4));
+        assert_eq!(bounds.volume(), 36);
+        assert_eq!(Bounds::<Point2>::of([]), None);
+    }
+
+    #[test]
+    fn parses_points() -> anyhow::Result<()> {
+        assert_eq!(parse_all("7,-1", point2)?, Point2::new(7, -1));
+        assert_eq!(
+            parse_all("162,817,812", point3)?,
+            Point3::new(162, 817, 812)
+        );
+        assert!(parse_all("1,2", point3).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::ParseError;
+
+/// The steps to the 4 orthogonal neighbours of a cell, as `(row, column)`.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The steps to all 8 neighbours of a cell, including the diagonals.
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, indexed by `(row, column)` from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A grid of `rows`, or the index of the first row that isn't as long as
+    /// the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, usize> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if let Some(idx) = rows.iter().position(|row| row.len() != width) {
+            return Err(idx);
+        }
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Parse a map with one character per cell, converted by `cell` or
+    /// rejected with the message it returns. Rows must all be the same width.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |row| row.chars().count());
+        if width == 0 {
+            return Err(ParseError::at(input, input, "expected a row of the grid"));
+        }
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for row in input.lines() {
+            if row.chars().count() != width {
+                let message = format!("expected {width} columns, like the first row");
+                return Err(ParseError::at(input, row, message));
+            }
+            for (idx, c) in row.char_indices() {
+                cells.push(cell(c).map_err(|message| ParseError::at(input, &row[idx..], message))?);
+            }
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(row, column)`, or `None` if it's outside the grid.
+    pub fn get(&self, (row, column): (usize, usize)) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): (usize, usize)) -> Option<&mut T> {
+        (row < self.height && column < self.width)
+            .then(|| &mut self.cells[row * self.width + column])
+    }
+
+    /// The position `step` away from `position`, if it's in the grid.
+    pub fn step(&self, position: (usize, usize), step: (isize, isize)) -> Option<(usize, usize)> {
+        step_within((self.width, self.height), position, step)
+    }
+
+    /// A copy of the grid with a border of `fill` cells around it, so that
+    /// the neighbours of every original cell can be indexed without checks.
+    /// The cell that was at `(row, column)` moves to `(row + 1, column + 1)`.
+    pub fn padded(&self, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut padded = Self::new(self.width + 2, self.height + 2, fill);
+        for (row, cells) in self.rows().enumerate() {
+            let start = (row + 1) * padded.width + 1;
+            padded.cells[start..start + self.width].clone_from_slice(cells);
+        }
+        padded
+    }
+
+    /// The positions of the cells above, left of, right of and below
+    /// `position` that are in the grid.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(position, ORTHOGONAL)
+    }
+
+    /// The positions of the up to 8 cells around `position`, including the
+    /// diagonals.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(position, ALL_AROUND)
+    }
+
+    /// The neighbours don't borrow the grid, so its cells can be updated
+    /// while they're visited.
+    fn neighbours<const N: usize>(
+        &self,
+        position: (usize, usize),
+        steps: [(isize, isize); N],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T, N> {
+        let size = (self.width, self.height);
+        steps
+            .into_iter()
+            .filter_map(move |step| step_within(size, position, step))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` needs a non-zero size, and an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {column} is outside the grid");
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Every cell with its position, a row at a time.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| ((idx / width, idx % width), cell))
+    }
+
+    /// The position of the first cell equal to `value`, a row at a time.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.cells()
+            .find(|(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// The grid flipped over its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+fn step_within(
+    (width, height): (usize, usize),
+    (row, column): (usize, usize),
+    (d_row, d_column): (isize, isize),
+) -> Option<(usize, usize)> {
+    let row = row.checked_add_signed(d_row).filter(|&row| row < height)?;
+    let column = column
+        .checked_add_signed(d_column)
+        .filter(|&column| column < width)?;
+    Some((row, column))
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+/// Write the grid back out as the text it was parsed from, a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid;
+
+    const MAP: &str = "#.S\n..#\n";
+
+    #[test]
+    fn parses_and_displays_maps() -> anyhow::Result<()> {
+        let grid = Grid::parse(MAP, Ok)?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], '#');
+        assert_eq!(grid.find(&'S'), Some((0, 2)));
+        assert_eq!(grid.to_string(), MAP);
+
+        let err = Grid::parse("..\n.\n", Ok).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = Grid::parse("..\n.x\n", |c| match c {
+            '.' => Ok(false),
+            c => Err(format!("found {c:?}")),
+        })
+        .unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (2, 2, "found 'x'")
+        );
+        assert!(Grid::parse("", Ok).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn access_is_bounds_checked() -> anyhow::Result<()> {
+        let grid = Grid::parse(MAP, Ok)?;
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((0, 0), (1, 1)), Some((1, 1)));
+
+        let padded = grid.padded('~');
+        assert_eq!(padded.to_string(), "~~~~~\n~#.S~\n~..#~\n~~~~~\n");
+        Ok(())
+    }
+
+    #[test]
+    fn neighbours_stay_in_the_grid() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 1)).collect::<Vec<_>>(),
+            [(0, 0), (0, 2), (1, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            [(1, 1), (1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(
+            transposed.rows().collect::<Vec<_>>(),
+            [[1, 4], [2, 5], [3, 6]]
+        );
+        assert_eq!(transposed.map(|n| n * 10)[(2, 1)], 60);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), Err(1));
+    }
+}
@@ -0,0 +1,257 @@
+use std::ops::RangeInclusive;
+
+/// Integers that an [`IntervalSet`] can hold, where each value has a next
+/// and previous one so that ranges which touch can be merged.
+pub trait Discrete: Copy + Ord {
+    /// The value after this one, or `None` for the largest.
+    fn succ(self) -> Option<Self>;
+
+    /// The value before this one, or `None` for the smallest.
+    fn pred(self) -> Option<Self>;
+
+    /// How many values there are from `start` to `end`, inclusive. That's
+    /// a `u128` so that the whole of a 64-bit type can be counted.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty)+) => {
+        $(
+            impl Discrete for $t {
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    end.abs_diff(start) as u128 + 1
+                }
+            }
+        )+
+    };
+}
+
+impl_discrete!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+/// A set of integers held as the sorted, disjoint ranges that cover it.
+///
+/// Ranges that overlap or touch are merged as they're inserted, so each is
+/// separated from the next by at least one value that isn't in the set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every value in `range`, merging it with any ranges it overlaps
+    /// or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // The ranges from `first` up to `last` overlap or touch `range`.
+        let first = self
+            .ranges
+            .partition_point(|r| r.end().succ().is_some_and(|after| after < start));
+        let last = self
+            .ranges
+            .partition_point(|r| end.succ().is_none_or(|after| *r.start() <= after));
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    /// Take out every value in `range`, splitting any range it falls inside.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| *r.end() < start);
+        let last = self.ranges.partition_point(|r| *r.start() <= end);
+        if first == last {
+            return;
+        }
+        // What's left either side of `range` is kept.
+        let before = (*self.ranges[first].start() < start)
+            .then(|| *self.ranges[first].start()..=start.pred().unwrap());
+        let after = (*self.ranges[last - 1].end() > end)
+            .then(|| end.succ().unwrap()..=*self.ranges[last - 1].end());
+        self.ranges
+            .splice(first..last, before.into_iter().chain(after));
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let idx = self.ranges.partition_point(|r| r.end() < value);
+        self.ranges.get(idx).is_some_and(|r| r.start() <= value)
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut ours, mut theirs) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(a), Some(b)) = (ours.peek(), theirs.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+            // Whichever ends first can't overlap anything else.
+            if a.end() < b.end() {
+                ours.next();
+            } else {
+                theirs.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// The values in `within` that aren't in this set.
+    pub fn complement(&self, within: RangeInclusive<T>) -> Self {
+        Self::from_iter([within]).difference(self)
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|r| T::count(*r.start(), *r.end()))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges covering the set, in order.
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().cloned()
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::RangeInclusive;
+
+    use super::IntervalSet;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().map(|&(start, end)| start..=end).collect()
+    }
+
+    #[test]
+    fn overlapping_and_touching_ranges_merge() {
+        let mut ranges = set(&[(10, 14), (3, 5), (16, 20), (12, 18)]);
+        assert_eq!(ranges.ranges(), [3..=5, 10..=20]);
+        ranges.insert(6..=8);
+        assert_eq!(ranges.ranges(), [3..=8, 10..=20]);
+        ranges.insert(9..=9);
+        assert_eq!(ranges.ranges(), [3..=20]);
+        // Empty ranges, as parsed from `5-1`, add nothing.
+        ranges.insert(RangeInclusive::new(5, 1));
+        assert_eq!(ranges.len(), 18);
+
+        let mut edges: IntervalSet<u8> = [250..=255, 0..=3].into_iter().collect();
+        edges.insert(4..=249);
+        assert_eq!(edges.ranges(), [0..=255]);
+    }
+
+    #[test]
+    fn full_ranges_are_counted() {
+        let unsigned: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(unsigned.len(), u128::from(u64::MAX) + 1);
+        let signed: IntervalSet<i64> = [i64::MIN..=i64::MAX].into_iter().collect();
+        assert_eq!(signed.len(), 1 << 64);
+    }
+
+    #[test]
+    fn removing_splits_ranges() {
+        let mut ranges = set(&[(3, 5), (10, 20)]);
+        ranges.remove(12..=14);
+        assert_eq!(ranges.ranges(), [3..=5, 10..=11, 15..=20]);
+        ranges.remove(0..=10);
+        assert_eq!(ranges.ranges(), [11..=11, 15..=20]);
+        ranges.remove(15..=30);
+        assert_eq!(ranges.ranges(), [11..=11]);
+        ranges.remove(11..=11);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn membership() {
+        let ranges = set(&[(3, 5), (10, 20)]);
+        for (value, expected) in [
+            (2, false),
+            (3, true),
+            (5, true),
+            (6, false),
+            (20, true),
+            (21, false),
+        ] {
+            assert_eq!(ranges.contains(&value), expected, "{value}");
+        }
+        assert!(!IntervalSet::new().contains(&0));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (15, 20)]);
+        assert_eq!(a.union(&b), set(&[(1, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 5), (10, 11), (15, 15)]));
+        assert_eq!(a.difference(&b), set(&[(1, 3), (12, 14)]));
+        assert_eq!(a.complement(0..=12), set(&[(0, 0), (6, 9)]));
+        assert_eq!(a.iter().collect::<Vec<_>>(), a.ranges().to_vec());
+    }
+}
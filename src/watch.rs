@@ -0,0 +1,105 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::util::Answer;
+
+/// How often the watched file is checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Polls a file's modification time to notice when it changes.
+#[derive(Debug)]
+pub struct Watcher {
+    path: PathBuf,
+    /// The modification time when last polled, `None` if the file was missing.
+    last_modified: Option<Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_owned(),
+            last_modified: None,
+        }
+    }
+
+    /// Whether the file has changed since the last poll, which the first
+    /// poll always reports so there's an initial run.
+    pub fn poll(&mut self) -> bool {
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|meta| meta.modified())
+            .ok();
+        let changed = self.last_modified != Some(modified);
+        self.last_modified = Some(modified);
+        changed
+    }
+}
+
+/// Describe how the answers changed from a previous run of the same day.
+pub fn diff(previous: &Answer, current: &Answer) -> String {
+    let mut out = String::new();
+    for (label, before, after) in [
+        ("Part one", previous.part_one(), current.part_one()),
+        ("Part two", previous.part_two(), current.part_two()),
+    ] {
+        match (before, after) {
+            (Some(before), Some(after)) if before != after => {
+                writeln!(out, "{label} changed: {before} -> {after}")
+            }
+            (None, Some(after)) => writeln!(out, "{label} is new: {after}"),
+            (Some(before), None) => writeln!(out, "{label} is gone, was {before}"),
+            _ => Ok(()),
+        }
+        .unwrap();
+    }
+    if out.is_empty() {
+        out.push_str("Answers unchanged.\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use super::{Watcher, diff};
+    use crate::util::{Answer, Parts, temp_dir};
+
+    #[test]
+    fn changes_are_noticed() -> anyhow::Result<()> {
+        let path = temp_dir("watch").join("input.txt");
+        let mut watcher = Watcher::new(&path);
+        // A missing file still gets an initial run, to report the error.
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+
+        std::fs::write(&path, "1\n")?;
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+
+        let file = std::fs::File::options().write(true).open(&path)?;
+        file.set_modified(SystemTime::now() + Duration::from_secs(5))?;
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+        Ok(())
+    }
+
+    #[test]
+    fn answers_are_diffed() {
+        let before = Answer::new(8, Parts::Both).first(|| 40).second(|| 7);
+        let same = Answer::new(8, Parts::Both).first(|| 40).second(|| 7);
+        assert_eq!(diff(&before, &same), "Answers unchanged.\n");
+
+        let after = Answer::new(8, Parts::One).first(|| 41);
+        assert_eq!(
+            diff(&before, &after),
+            "Part one changed: 40 -> 41\nPart two is gone, was 7\n"
+        );
+        assert_eq!(
+            diff(&after, &before),
+            "Part one changed: 41 -> 40\nPart two is new: 7\n"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    Value,
+    solver::{Example, Solver},
+};
+
+pub struct Day{{NN}};
+
+const EXAMPLE: &str = "\
+";
+
+impl Solver for Day{{NN}} {
+    const YEAR: u16 = {{YEAR}};
+    const DAY: usize = {{DAY}};
+    const TITLE: &'static str = "{{TITLE}}";
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: EXAMPLE,
+        part_one: None,
+        part_two: None,
+    }];
+
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(&self, lines: &mut Self::Parsed) -> anyhow::Result<impl Into<Value>> {
+        Ok(solve_part_one(lines))
+    }
+}
+
+fn parse_input(input: &str) -> Vec<String> {
+    input.lines().map(str::to_owned).collect()
+}
+
+fn solve_part_one(lines: &[String]) -> usize {
+    lines.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::EXAMPLE;
+
+    #[test]
+    fn part_one_test_input() {
+        let lines = super::parse_input(EXAMPLE);
+        let res = super::solve_part_one(&lines);
+        assert_eq!(res, 0);
+    }
+}